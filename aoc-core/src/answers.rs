@@ -0,0 +1,156 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::{Answer, Part};
+
+/// File in every day's directory recording the expected answers, with a
+/// table per input file:
+///
+/// ```toml
+/// ["example.txt"]
+/// part1 = 4361
+/// part2 = 467835
+/// ```
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The answers recorded for a single input file. Either part may be missing,
+/// e.g. when an example only applies to one part.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    /// Compares a computed answer to the recorded one, if there is any.
+    pub fn check(&self, part: Part, answer: &Answer) -> Check {
+        match self.get(part) {
+            Some(expected) if expected == answer.to_string() => Check::Match,
+            Some(expected) => Check::Mismatch(expected.to_string()),
+            None => Check::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Match,
+    /// The answer differs from the recorded one, which is included.
+    Mismatch(String),
+    /// No answer was recorded for this part.
+    Unknown,
+}
+
+impl Check {
+    pub fn is_mismatch(&self) -> bool {
+        matches!(self, Check::Mismatch(_))
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Match => write!(f, "ok"),
+            Check::Mismatch(expected) => write!(f, "expected {}", expected),
+            Check::Unknown => write!(f, "no answer recorded"),
+        }
+    }
+}
+
+/// Loads the answers recorded in `directory` for `input`, looked up by its
+/// file name. Without an answers file, nothing is expected.
+pub fn expected_answers(directory: &Path, input: &Path) -> Result<Expected, String> {
+    let path = directory.join(ANSWERS_FILE);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Expected::default()),
+        Err(e) => return Err(format!("Could not read {}: {}", path.display(), e)),
+    };
+
+    let file_name = input
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    parse_answers(&contents, file_name).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn parse_answers(contents: &str, file_name: &str) -> Result<Expected, String> {
+    let table: toml::Table = contents
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+    let Some(answers) = table.get(file_name) else {
+        return Ok(Expected::default());
+    };
+    let answers = answers
+        .as_table()
+        .ok_or_else(|| format!("[\"{}\"] should be a table", file_name))?;
+
+    let answer = |key: &str| match answers.get(key) {
+        None => Ok(None),
+        Some(toml::Value::Integer(n)) => Ok(Some(n.to_string())),
+        Some(toml::Value::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(format!(
+            "{} of \"{}\" should be a number or a string",
+            key, file_name
+        )),
+    };
+
+    Ok(Expected {
+        part1: answer("part1")?,
+        part2: answer("part2")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+["example.txt"]
+part1 = 4361
+part2 = "467835"
+
+["example2.txt"]
+part2 = 8
+"#;
+
+    #[test]
+    fn test_parse_answers() {
+        let expected = parse_answers(ANSWERS, "example.txt").unwrap();
+        assert_eq!(expected.get(Part::One), Some("4361"));
+        assert_eq!(expected.get(Part::Two), Some("467835"));
+
+        let expected = parse_answers(ANSWERS, "example2.txt").unwrap();
+        assert_eq!(expected.get(Part::One), None);
+
+        assert_eq!(parse_answers(ANSWERS, "input.txt"), Ok(Expected::default()));
+        assert!(parse_answers("[\"input.txt\"]\npart1 = 1.5", "input.txt").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = parse_answers(ANSWERS, "example.txt").unwrap();
+
+        assert_eq!(
+            expected.check(Part::One, &Answer::Number(4361)),
+            Check::Match
+        );
+        assert_eq!(
+            expected.check(Part::Two, &Answer::Number(1)),
+            Check::Mismatch("467835".to_string())
+        );
+        assert_eq!(
+            Expected::default().check(Part::One, &Answer::Number(1)),
+            Check::Unknown
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::error::Error;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{Part, Solution};
+
+/// Summary of the timings of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Summarizes `samples`, which must not be empty.
+    pub fn new(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort_unstable();
+
+        // Nearest rank, so the p95 of a handful of samples is the slowest one
+        let rank = |percent: usize| (samples.len() * percent).div_ceil(100).max(1) - 1;
+        let total: Duration = samples.iter().sum();
+
+        Self {
+            min: samples[0],
+            median: samples[rank(50)],
+            p95: samples[rank(95)],
+            mean: total / samples.len() as u32,
+        }
+    }
+}
+
+/// Timings of parsing an input and solving each part from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub iterations: usize,
+    /// `None` for days that parse as part of solving, whose parse time is
+    /// included in every part's.
+    pub parse: Option<Stats>,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+impl fmt::Display for Bench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<8} {:>12} {:>12} {:>12} {:>12}",
+            "", "min", "median", "p95", "mean"
+        )?;
+
+        let parts = self
+            .parts
+            .iter()
+            .map(|(part, stats)| (format!("part {}", part), Some(stats)));
+        let rows = std::iter::once(("parse".to_string(), self.parse.as_ref())).chain(parts);
+        for (name, stats) in rows {
+            let columns = match stats {
+                Some(stats) => [stats.min, stats.median, stats.p95, stats.mean]
+                    .map(|duration| format!("{:.2?}", duration)),
+                None => ["n/a"; 4].map(String::from),
+            };
+            write!(
+                f,
+                "\n{:<8} {:>12} {:>12} {:>12} {:>12}",
+                name, columns[0], columns[1], columns[2], columns[3]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Number of untimed runs before `iterations` timed ones, to warm up caches
+/// and the branch predictor.
+pub fn warmup(iterations: usize) -> usize {
+    iterations.div_ceil(10)
+}
+
+/// Times [`Solution::prepare`] and solving `parts` from the prepared input,
+/// `iterations` times after a [`warmup`].
+pub fn bench(
+    solution: &dyn Solution,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Bench, Box<dyn Error>> {
+    if iterations == 0 {
+        return Err("a benchmark needs at least one iteration".into());
+    }
+
+    let mut parse = Vec::with_capacity(iterations);
+    let mut is_parsed = true;
+    let mut solves = vec![Vec::with_capacity(iterations); parts.len()];
+
+    for iteration in 0..warmup(iterations) + iterations {
+        let timed = iteration >= warmup(iterations);
+
+        let now = Instant::now();
+        let prepared = solution.prepare(black_box(input))?;
+        if timed {
+            parse.push(now.elapsed());
+        }
+        is_parsed = prepared.is_parsed();
+
+        for (&part, samples) in parts.iter().zip(&mut solves) {
+            let now = Instant::now();
+            black_box(prepared.solve(part)?);
+            if timed {
+                samples.push(now.elapsed());
+            }
+        }
+    }
+
+    Ok(Bench {
+        iterations,
+        parse: is_parsed.then(|| Stats::new(&mut parse)),
+        parts: parts
+            .iter()
+            .zip(&mut solves)
+            .map(|(&part, samples)| (part, Stats::new(samples)))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::new(&mut samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+
+        let stats = Stats::new(&mut [Duration::from_millis(3)]);
+        assert_eq!((stats.min, stats.p95), (stats.median, stats.mean));
+    }
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn name(&self) -> &'static str {
+            "lengths"
+        }
+
+        fn directory(&self) -> &'static str {
+            env!("CARGO_MANIFEST_DIR")
+        }
+
+        fn part1(&self, input: &str) -> crate::SolveResult {
+            Ok(input.len().into())
+        }
+
+        fn part2(&self, input: &str) -> crate::SolveResult {
+            Ok(input.lines().count().into())
+        }
+    }
+
+    #[test]
+    fn test_bench_unparsed() {
+        let timings = bench(&Lengths, "a\nb", &Part::BOTH, 3).unwrap();
+        assert_eq!(timings.parse, None);
+        assert_eq!(timings.parts.len(), 2);
+
+        let output = timings.to_string();
+        assert!(output.contains("\nparse             n/a          n/a          n/a          n/a\n"));
+        assert!(bench(&Lengths, "", &[Part::One], 0).is_err());
+    }
+
+    #[test]
+    fn test_warmup() {
+        assert_eq!(warmup(1), 1);
+        assert_eq!(warmup(100), 10);
+    }
+}
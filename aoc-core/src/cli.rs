@@ -0,0 +1,198 @@
+use std::io::Read;
+use std::path::Path;
+use std::time::Instant;
+
+use clap::Parser;
+
+use crate::answers::{expected_answers, Check};
+use crate::bench::{bench, warmup};
+use crate::output::{Format, Report};
+use crate::{Part, Solution};
+
+/// Options shared by every day's binary. Days with options of their own
+/// flatten these into their arguments, see [`parse`].
+#[derive(Debug, clap::Args)]
+pub struct Options {
+    /// Puzzle inputs, solved in sequence; `-` reads standard input
+    #[arg(required = true, value_name = "FILE")]
+    pub files: Vec<String>,
+
+    /// Only solve this part
+    #[arg(short, long, value_enum)]
+    pub part: Option<Part>,
+
+    /// Compare the answers to the ones recorded in the day's answers.toml
+    #[arg(long)]
+    pub check: bool,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Time parsing and solving over this many iterations instead
+    #[arg(long, value_name = "ITERATIONS", conflicts_with_all = ["check", "format"])]
+    pub bench: Option<usize>,
+}
+
+impl Options {
+    /// The parts to solve: the one asked for, or both.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::BOTH.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    options: Options,
+}
+
+/// Entry point for the per-day binaries: solves the parts asked for, both by
+/// default, for every file passed in ARGV. See [`run`] for the details.
+pub fn main(solution: &dyn Solution) {
+    let args: Args = parse(solution);
+    run(solution, &args.options);
+}
+
+/// Parses the command line of `solution`'s binary, exiting with usage on
+/// invalid arguments or `--help`.
+pub fn parse<A: Parser>(solution: &dyn Solution) -> A {
+    A::command()
+        .about(format!("Day {}: {}", solution.day(), solution.name()))
+        .try_get_matches()
+        .and_then(|matches| A::from_arg_matches(&matches))
+        .unwrap_or_else(|e| e.exit())
+}
+
+/// Solves every file in `options`, with a header per file when there are
+/// several. Malformed input is reported on stderr with a non-zero exit code,
+/// after trying the remaining files.
+///
+/// With `--check`, the answers are compared to the ones recorded in the day's
+/// `answers.toml`, failing if any of them differ. `--format json` prints a
+/// JSON object per file instead of text, and `--bench N` times parsing and
+/// solving over `N` iterations instead.
+pub fn run(solution: &dyn Solution, options: &Options) {
+    let headers = options.format == Format::Text;
+    let failed = for_each_file(&options.files, headers, |filename| {
+        solve_file(solution, filename, options)
+    });
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Runs `f` on every file, preceded by a `==> name <==` header when there
+/// are several and `headers` is set. Failures are reported on stderr and the
+/// remaining files are still tried; returns how many failed.
+pub fn for_each_file<T: AsRef<str>>(
+    files: &[T],
+    headers: bool,
+    mut f: impl FnMut(&T) -> Result<(), String>,
+) -> usize {
+    let mut failed = 0;
+    for (idx, file) in files.iter().enumerate() {
+        if headers && files.len() > 1 {
+            if idx > 0 {
+                println!();
+            }
+            println!("==> {} <==", file.as_ref());
+        }
+
+        if let Err(message) = f(file) {
+            eprintln!("{}", message);
+            failed += 1;
+        }
+    }
+
+    failed
+}
+
+/// Reads a puzzle input, from standard input when `filename` is `-`.
+pub fn read_input(filename: &str) -> Result<String, String> {
+    if filename == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("Could not read standard input: {}", e))?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(filename).map_err(|e| format!("Could not read {}: {}", filename, e))
+    }
+}
+
+/// Solves a single file as [`run`] does for each of them, ignoring
+/// `options.files`. Fails with the message to report when the file can't be
+/// read or solved, or when an answer doesn't match with `--check`.
+pub fn solve_file(
+    solution: &dyn Solution,
+    filename: &str,
+    options: &Options,
+) -> Result<(), String> {
+    let now = Instant::now();
+    let document = read_input(filename)?;
+
+    if let Some(iterations) = options.bench {
+        let bench = bench(solution, &document, &options.parts(), iterations)
+            .map_err(|e| format!("{}: {}", filename, e))?;
+        println!(
+            "{} iterations after {} warmup",
+            iterations,
+            warmup(iterations)
+        );
+        println!("{}", bench);
+        return Ok(());
+    }
+
+    let expected = if options.check {
+        Some(expected_answers(
+            Path::new(solution.directory()),
+            Path::new(filename),
+        )?)
+    } else {
+        None
+    };
+
+    let mut report = Report::new(solution.day());
+    let mut mismatches = 0;
+    for part in options.parts() {
+        let answer = solution
+            .solve(&document, part)
+            .map_err(|e| format!("{}: {}", filename, e))?;
+
+        let check = expected
+            .as_ref()
+            .map(|expected| expected.check(part, &answer));
+        if let Some(Check::Mismatch(expected)) = &check {
+            mismatches += 1;
+            // Keep stdout parseable in JSON mode
+            if options.format == Format::Json {
+                eprintln!("Part {}: {} (expected {})", part, answer, expected);
+            }
+        }
+
+        if options.format == Format::Text {
+            match check {
+                Some(check) => println!("Part {}: {} ({})", part, answer, check),
+                None => println!("Part {}: {}", part, answer),
+            }
+        }
+        report.set(part, answer);
+    }
+
+    report.elapsed = now.elapsed();
+    match options.format {
+        Format::Text => println!("Runtime: {:?}", report.elapsed),
+        Format::Json => println!("{}", report.to_json()),
+    }
+
+    match mismatches {
+        0 => Ok(()),
+        1 => Err(format!("{}: 1 answer did not match", filename)),
+        n => Err(format!("{}: {} answers did not match", filename, n)),
+    }
+}
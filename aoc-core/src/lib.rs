@@ -0,0 +1,257 @@
+use std::error::Error;
+use std::fmt;
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod output;
+pub mod parse;
+
+pub use parse::{parse_lines, ParseError};
+
+/// What solving a part returns: the answer, or why the input couldn't be
+/// solved.
+pub type SolveResult = Result<Answer, Box<dyn Error>>;
+
+/// What preparing an input returns: something to solve both parts with, or
+/// why the input couldn't be parsed.
+pub type PrepareResult<'a> = Result<Box<dyn Prepared + 'a>, Box<dyn Error>>;
+
+/// A single day of Advent of Code, solvable from the raw puzzle input.
+///
+/// Every day crate exposes a unit struct (`Day1`, `Day2`, ...) implementing
+/// this trait, so the `aoc` runner can dispatch to any of them.
+pub trait Solution {
+    /// Day of December the puzzle was released on.
+    fn day(&self) -> u8;
+
+    /// Short name of the puzzle, e.g. `"trebuchet"`.
+    fn name(&self) -> &'static str;
+
+    /// Directory of the crate, where `example.txt` and `input.txt` live.
+    fn directory(&self) -> &'static str;
+
+    fn part1(&self, input: &str) -> SolveResult;
+
+    fn part2(&self, input: &str) -> SolveResult;
+
+    fn solve(&self, input: &str, part: Part) -> SolveResult {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    /// Parses the input once, up front, so parsing can be timed apart from
+    /// solving. Days whose parts share a parser override this with [`Parsed`];
+    /// otherwise nothing happens here, every part parses as it solves, and
+    /// [`Prepared::is_parsed`] is false.
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Unparsed {
+            solution: self,
+            input,
+        }))
+    }
+}
+
+/// Puzzle input ready to be solved, see [`Solution::prepare`].
+pub trait Prepared {
+    fn part1(&self) -> SolveResult;
+
+    fn part2(&self) -> SolveResult;
+
+    /// Whether the input was parsed up front, rather than by every part as
+    /// it solves.
+    fn is_parsed(&self) -> bool {
+        true
+    }
+
+    fn solve(&self, part: Part) -> SolveResult {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+/// Parsed input along with the functions solving each part from it.
+pub struct Parsed<T, F1, F2> {
+    input: T,
+    part1: F1,
+    part2: F2,
+}
+
+impl<T, F1, F2> Parsed<T, F1, F2> {
+    pub fn new<A1, A2>(input: T, part1: F1, part2: F2) -> Self
+    where
+        F1: Fn(&T) -> A1,
+        F2: Fn(&T) -> A2,
+    {
+        Self {
+            input,
+            part1,
+            part2,
+        }
+    }
+}
+
+impl<T, F1, F2, A1, A2> Prepared for Parsed<T, F1, F2>
+where
+    F1: Fn(&T) -> A1,
+    F2: Fn(&T) -> A2,
+    A1: IntoSolveResult,
+    A2: IntoSolveResult,
+{
+    fn part1(&self) -> SolveResult {
+        (self.part1)(&self.input).into_solve_result()
+    }
+
+    fn part2(&self) -> SolveResult {
+        (self.part2)(&self.input).into_solve_result()
+    }
+}
+
+/// What the functions solving a part can return: an answer, or a result
+/// with one.
+pub trait IntoSolveResult {
+    fn into_solve_result(self) -> SolveResult;
+}
+
+impl<T: Into<Answer>> IntoSolveResult for T {
+    fn into_solve_result(self) -> SolveResult {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>, E: Error + 'static> IntoSolveResult for Result<T, E> {
+    fn into_solve_result(self) -> SolveResult {
+        Ok(self?.into())
+    }
+}
+
+// The raw input, for days that parse as part of solving
+struct Unparsed<'a, S: ?Sized> {
+    solution: &'a S,
+    input: &'a str,
+}
+
+impl<S: Solution + ?Sized> Prepared for Unparsed<'_, S> {
+    fn part1(&self) -> SolveResult {
+        self.solution.part1(self.input)
+    }
+
+    fn part2(&self) -> SolveResult {
+        self.solution.part2(self.input)
+    }
+
+    fn is_parsed(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle.
+///
+/// All puzzles so far have numeric answers, but they come in every integer
+/// type under the sun, so they're widened to `i128` here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, usize, isize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from_numbers() {
+        assert_eq!(Answer::from(42_u32), Answer::Number(42));
+        assert_eq!(Answer::from(-7_i64), Answer::Number(-7));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_parsed() {
+        let parsed = Parsed::new(
+            vec![1, 2, 3],
+            |n: &Vec<i32>| n.len(),
+            |n: &Vec<i32>| n.iter().sum::<i32>(),
+        );
+
+        assert_eq!(parsed.solve(Part::One).unwrap(), Answer::Number(3));
+        assert_eq!(parsed.solve(Part::Two).unwrap(), Answer::Number(6));
+
+        let parsed = Parsed::new(
+            "x",
+            |_: &&str| 1,
+            |s: &&str| Err::<u8, _>(ParseError::new("not a number", s, s)),
+        );
+        assert_eq!(parsed.solve(Part::One).unwrap(), Answer::Number(1));
+        assert!(parsed.solve(Part::Two).is_err());
+    }
+}
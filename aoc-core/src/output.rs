@@ -0,0 +1,104 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::{Answer, Part};
+
+/// How the binaries print their answers. The variants' docs double as the
+/// `--format` help.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Format {
+    /// Human readable lines
+    #[default]
+    Text,
+    /// A JSON object per solved input, with the day, answers and elapsed time
+    Json,
+}
+
+/// The answers to a day for one input, along with how long they took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u8,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub elapsed: Duration,
+}
+
+impl Report {
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            part1: None,
+            part2: None,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Answer) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    /// Renders the report as
+    /// `{"day":N,"part1":...,"part2":...,"elapsed_ns":...}`, on a single
+    /// line. Parts that weren't solved are `null`.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"day\":{},\"part1\":{},\"part2\":{},\"elapsed_ns\":{}}}",
+            self.day,
+            json_answer(self.part1.as_ref()),
+            json_answer(self.part2.as_ref()),
+            self.elapsed.as_nanos()
+        )
+    }
+}
+
+fn json_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        None => "null".to_string(),
+        Some(Answer::Number(n)) => n.to_string(),
+        Some(Answer::Text(s)) => json_string(s),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_to_json() {
+        let mut report = Report::new(3);
+        report.set(Part::One, Answer::Number(4361));
+        report.elapsed = Duration::from_micros(12);
+
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":3,"part1":4361,"part2":null,"elapsed_ns":12000}"#
+        );
+
+        report.set(Part::Two, Answer::Text("say \"hi\"\n".to_string()));
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":3,"part1":4361,"part2":"say \"hi\"\n","elapsed_ns":12000}"#
+        );
+    }
+}
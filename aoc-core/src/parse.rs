@@ -0,0 +1,125 @@
+use std::error::Error;
+use std::fmt;
+
+/// Malformed puzzle input, pointing at the offending text.
+///
+/// `K` is the kind of error, an enum defined by every day for the mistakes
+/// its parsers can find.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<K> {
+    pub kind: K,
+    /// Line of the input the error is on, starting at 1.
+    pub line: usize,
+    /// Byte offset within that line, starting at 1.
+    pub column: usize,
+    pub text: String,
+}
+
+impl<K> ParseError<K> {
+    /// An error about `text`, which must be a slice of `line`. Parsers work on
+    /// a single line, so the error starts out on line 1; see [`Self::below`].
+    ///
+    /// To point past the end of the line, e.g. for missing text, pass
+    /// `&line[line.len()..]`.
+    pub fn new(kind: K, line: &str, text: &str) -> Self {
+        Self {
+            kind,
+            line: 1,
+            column: column(line, text),
+            text: text.to_string(),
+        }
+    }
+
+    /// Moves the error down by `lines`, for input that started further down
+    /// the document.
+    pub fn below(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Re-anchors an error found while parsing `text`, a slice of `line`, so
+    /// its column is relative to `line` instead.
+    pub fn within(mut self, line: &str, text: &str) -> Self {
+        self.column += column(line, text) - 1;
+        self
+    }
+}
+
+impl<K: fmt::Display> fmt::Display for ParseError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: fmt::Debug + fmt::Display> Error for ParseError<K> {}
+
+// Position of `text` within `line`, falling back to the start of the line
+// when it isn't a slice of it
+fn column(line: &str, text: &str) -> usize {
+    let offset = (text.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+
+    if offset <= line.len() {
+        offset + 1
+    } else {
+        1
+    }
+}
+
+/// Parses every line of `document` with `parse`, fixing up the line numbers
+/// of any error.
+pub fn parse_lines<'a, T, K, F>(document: &'a str, parse: F) -> Result<Vec<T>, ParseError<K>>
+where
+    F: Fn(&'a str) -> Result<T, ParseError<K>>,
+{
+    document
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|e| e.below(idx)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_column() {
+        let line = "Game 1: 3 blue";
+        let error = ParseError::new("bad colour", line, &line[10..]);
+
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 11);
+        assert_eq!(error.to_string(), "line 1, column 11: bad colour \"blue\"");
+
+        let error = ParseError::new("missing", line, &line[line.len()..]);
+        assert_eq!(error.column, 15);
+        assert_eq!(error.to_string(), "line 1, column 15: missing");
+    }
+
+    #[test]
+    fn test_parse_error_within() {
+        let line = "Game 1: 3 blue";
+        let turn = &line[8..];
+        let error = ParseError::new("bad colour", turn, &turn[2..]).within(line, turn);
+
+        assert_eq!(error.column, 11);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let result = parse_lines("1\n2\nx", |line| {
+            line.parse::<u8>()
+                .map_err(|_| ParseError::new("not a number", line, line))
+        });
+
+        assert_eq!(result.unwrap_err().line, 3);
+    }
+}
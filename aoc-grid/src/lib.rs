@@ -0,0 +1,376 @@
+//! A two dimensional grid, for the many puzzles drawn as characters on a map.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_core::ParseError;
+
+/// A position in a grid, as `(row, column)` from the top left corner.
+pub type Point = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All four directions, clockwise starting at north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// The direction a quarter turn to the right.
+    pub fn clockwise(&self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// Change in `(row, column)` of a single step.
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given cells, row by row. Panics unless they fill whole
+    /// rows of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells don't fill whole rows");
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with a row per line of `text`, and `cell` turning every
+    /// character into a cell. Lines of a different length than the first one
+    /// are reported as `ragged`.
+    pub fn parse<K, F>(text: &str, ragged: K, mut cell: F) -> Result<Self, ParseError<K>>
+    where
+        F: FnMut(char) -> Result<T, K>,
+    {
+        let mut lines = text.lines().enumerate().peekable();
+        let width = lines.peek().map_or(0, |(_, line)| line.chars().count());
+        let mut cells = Vec::with_capacity(width * text.len() / (width + 1).max(1));
+
+        for (row, line) in lines {
+            let mut columns = 0;
+            for (idx, c) in line.char_indices() {
+                let parsed = cell(c).map_err(|kind| {
+                    ParseError::new(kind, line, &line[idx..idx + c.len_utf8()]).below(row)
+                })?;
+                cells.push(parsed);
+                columns += 1;
+            }
+
+            if columns != width {
+                return Err(ParseError::new(ragged, line, line).below(row));
+            }
+        }
+
+        Ok(Self::from_cells(width, cells))
+    }
+
+    /// Like [`Grid::parse`], but short lines are padded with `fill` up to the
+    /// longest one instead.
+    pub fn parse_padded<F>(text: &str, fill: T, mut cell: F) -> Self
+    where
+        F: FnMut(char) -> T,
+        T: Clone,
+    {
+        let width = text
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * text.lines().count());
+
+        for line in text.lines() {
+            let start = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            cells.resize(start + width, fill.clone());
+        }
+
+        Self::from_cells(width, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Point) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[self.idx(point)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            let idx = self.idx(point);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Moves from `point` by `(rows, columns)`, or `None` when that leaves
+    /// the grid.
+    pub fn offset(&self, (row, column): Point, (rows, columns): (isize, isize)) -> Option<Point> {
+        let point = (
+            row.checked_add_signed(rows)?,
+            column.checked_add_signed(columns)?,
+        );
+
+        self.contains(point).then_some(point)
+    }
+
+    /// Steps one cell in `direction`, or `None` when that leaves the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.offset(point, direction.offset())
+    }
+
+    /// The up to four points sharing a side with `point`, clockwise starting
+    /// at north.
+    pub fn neighbors(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The up to eight points sharing a side or a corner with `point`.
+    pub fn neighbors_diagonal(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        (-1..=1)
+            .flat_map(|rows| (-1..=1).map(move |columns| (rows, columns)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks_exact panics on a width of 0, which has no rows anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(column < self.width, "column {} out of bounds", column);
+        self.cells[column..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Every point, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Every cell along with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid::from_cells(self.width, self.cells.iter().map(f).collect())
+    }
+
+    fn idx(&self, (row, column): Point) -> usize {
+        row * self.width + column
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self::from_cells(width, vec![fill; width * height])
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flatten().cloned().collect();
+        Self::from_cells(self.height, cells)
+    }
+
+    /// The grid turned a quarter clockwise, so the left column becomes the
+    /// top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = self.columns().flat_map(Iterator::rev).cloned().collect();
+        Self::from_cells(self.height, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        assert!(self.contains(point), "{:?} out of bounds", point);
+        &self.cells[self.idx(point)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        assert!(self.contains(point), "{:?} out of bounds", point);
+        let idx = self.idx(point);
+        &mut self.cells[idx]
+    }
+}
+
+/// Draws the grid a row per line, the way it is parsed.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", "ragged", Ok::<_, &str>).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+
+        let error = Grid::parse("ab\nc", "ragged", Ok::<_, &str>).unwrap_err();
+        assert_eq!((error.kind, error.line), ("ragged", 2));
+
+        let error = Grid::parse("ab\ncx", "ragged", |c| match c {
+            'x' => Err("not a letter"),
+            c => Ok(c),
+        })
+        .unwrap_err();
+        assert_eq!(
+            (error.kind, error.line, error.column),
+            ("not a letter", 2, 2)
+        );
+
+        let grid = Grid::parse("", "ragged", Ok::<char, &str>).unwrap();
+        assert_eq!(
+            (grid.width(), grid.height(), grid.rows().count()),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::parse_padded("ab\nc\n", '.', |c| c);
+        assert_eq!(grid.to_string(), "ab\nc.");
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = grid();
+        assert_eq!(grid.step((0, 0), Direction::East), Some((0, 1)));
+        assert_eq!(grid.step((0, 0), Direction::North), None);
+        assert_eq!(grid.step((1, 2), Direction::East), None);
+        assert_eq!(grid.offset((1, 2), (-1, -2)), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors((0, 1)).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 1), (0, 0)]
+        );
+        assert_eq!(grid.neighbors_diagonal((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors_diagonal((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', 'c'], &['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).rev().collect::<String>(), "eb");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid = grid();
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
@@ -0,0 +1,194 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::cli::{for_each_file, solve_file, Options};
+use aoc_core::output::Format;
+use aoc_core::{Part, Solution};
+use clap::{Parser, Subcommand};
+
+fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(trebuchet::Day1::default()),
+        Box::new(cubes::Day2::default()),
+        Box::new(gear_ratios::Day3),
+        Box::new(scratchcard::Day4::default()),
+        Box::new(seeds::Day5),
+        Box::new(race::Day6),
+        Box::new(cards::Day7),
+        Box::new(day8_maps::Day8),
+        Box::new(day9_sand::Day9),
+        Box::new(day10_pipes::Day10),
+        Box::new(day11_galaxies::Day11::default()),
+        Box::new(day12_springs::Day12::default()),
+        Box::new(day13_mirrors::Day13::default()),
+        Box::new(day14_rocks::Day14),
+        Box::new(day15_hash::Day15),
+        Box::new(day16_beams::Day16),
+        Box::new(day18_lagoon::Day18),
+        Box::new(day19_workflows::Day19),
+    ]
+}
+
+/// Advent of Code 2023: solves any day's puzzle.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day
+    Run(RunOptions),
+    /// List the days there are solutions for
+    List,
+}
+
+#[derive(clap::Args)]
+struct RunOptions {
+    /// Day to solve, or `all`
+    target: String,
+
+    /// Inputs to solve in sequence, `-` reading standard input [default: the
+    /// day's input.txt]
+    #[arg(value_name = "FILE")]
+    files: Vec<String>,
+
+    /// Only solve this part
+    #[arg(short, long, value_enum)]
+    part: Option<Part>,
+
+    /// Solve the day's example.txt instead of its input.txt
+    #[arg(long, conflicts_with = "files")]
+    example: bool,
+
+    /// Compare the answers to the ones recorded in the day's answers.toml
+    #[arg(long)]
+    check: bool,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Time parsing and solving over this many iterations instead, after a
+    /// few warmup runs
+    #[arg(long, value_name = "ITERATIONS", conflicts_with_all = ["check", "format"])]
+    bench: Option<usize>,
+}
+
+impl RunOptions {
+    // The options every day's binary takes, to solve inputs the same way
+    fn shared(&self) -> Options {
+        Options {
+            files: Vec::new(),
+            part: self.part,
+            check: self.check,
+            format: self.format,
+            bench: self.bench,
+        }
+    }
+}
+
+// A file to solve and the day it's an input of
+struct Input<'a> {
+    solution: &'a dyn Solution,
+    filename: String,
+}
+
+impl<'a> Input<'a> {
+    fn new(solution: &'a dyn Solution, path: &Path) -> Self {
+        Self {
+            solution,
+            filename: path.to_string_lossy().into_owned(),
+        }
+    }
+}
+
+impl AsRef<str> for Input<'_> {
+    fn as_ref(&self) -> &str {
+        &self.filename
+    }
+}
+
+fn main() {
+    let result = match Cli::parse().command {
+        Command::Run(options) => run(&options),
+        Command::List => {
+            list();
+            Ok(())
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
+}
+
+fn list() {
+    for solution in solutions() {
+        println!("{:>2}  {}", solution.day(), solution.name());
+    }
+}
+
+fn run(options: &RunOptions) -> Result<(), String> {
+    let solutions = solutions();
+    let mut inputs: Vec<Input> = Vec::new();
+
+    if options.target == "all" {
+        if !options.files.is_empty() {
+            return Err("Files can only be given when running a single day".to_string());
+        }
+
+        for solution in &solutions {
+            let path = default_input(solution.as_ref(), options.example);
+            if path.exists() {
+                inputs.push(Input::new(solution.as_ref(), &path));
+            } else if options.format == Format::Json {
+                eprintln!("Day {:>2}: no input at {}", solution.day(), path.display());
+            } else {
+                println!("Day {:>2}: no input at {}", solution.day(), path.display());
+            }
+        }
+    } else {
+        let day: u8 = options
+            .target
+            .parse()
+            .map_err(|_| format!("Not a day: {}", options.target))?;
+        let solution = solutions
+            .iter()
+            .find(|solution| solution.day() == day)
+            .ok_or_else(|| format!("No solution for day {}", day))?
+            .as_ref();
+
+        if options.files.is_empty() {
+            let path = default_input(solution, options.example);
+            inputs.push(Input::new(solution, &path));
+        } else {
+            inputs.extend(
+                options
+                    .files
+                    .iter()
+                    .map(|file| Input::new(solution, Path::new(file))),
+            );
+        }
+    }
+
+    // Like the day binaries, keep going past inputs that fail
+    let shared = options.shared();
+    let headers = options.format == Format::Text;
+    let failed = for_each_file(&inputs, headers, |input| {
+        solve_file(input.solution, &input.filename, &shared)
+    });
+
+    match failed {
+        0 => Ok(()),
+        1 => Err("1 input failed".to_string()),
+        n => Err(format!("{} inputs failed", n)),
+    }
+}
+
+fn default_input(solution: &dyn Solution, example: bool) -> PathBuf {
+    let filename = if example { "example.txt" } else { "input.txt" };
+    Path::new(solution.directory()).join(filename)
+}
@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use trebuchet::{sum_document, sum_reader, Vocabulary};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+/// Size of the generated document, large enough for the scanner to dominate.
+const DOCUMENT_SIZE: usize = 4 << 20;
+
+fn bench_sum_document(c: &mut Criterion) {
+    let document = EXAMPLE.repeat(DOCUMENT_SIZE / EXAMPLE.len());
+    let vocabulary = Vocabulary::default();
+
+    let mut group = c.benchmark_group("scanner");
+    group.throughput(Throughput::Bytes(document.len() as u64));
+    group.sample_size(20);
+    group.bench_function("sum_document", |b| {
+        b.iter(|| sum_document(black_box(&document), &vocabulary))
+    });
+    group.bench_function("sum_reader", |b| {
+        b.iter(|| sum_reader::<u64, _>(black_box(document.as_bytes()), &vocabulary))
+    });
+    #[cfg(feature = "rayon")]
+    group.bench_function("sum_document_parallel", |b| {
+        b.iter(|| trebuchet::sum_document_parallel::<u64>(black_box(&document), &vocabulary))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_sum_document);
+criterion_main!(benches);
@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::scanner::Token;
+use crate::trebuchet::calibration_value;
+use crate::vocabulary::Vocabulary;
+
+/// How the calibration value of a single line came to be.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    /// Line number, starting at 1.
+    pub number: usize,
+    pub line: &'a str,
+    /// Every number on the line, including overlapping ones.
+    pub tokens: Vec<Token>,
+    /// The first and last number, unless the line has none.
+    pub chosen: Option<(Token, Token)>,
+}
+
+impl Explanation<'_> {
+    /// The calibration value, unless the line has no digits.
+    pub fn value(&self) -> Option<u32> {
+        self.chosen
+            .as_ref()
+            .map(|(first, last)| calibration_value(first, last))
+    }
+}
+
+/// Explains every line of `document`, see [`explain_line`].
+pub fn explain_document<'a>(document: &'a str, vocabulary: &Vocabulary) -> Vec<Explanation<'a>> {
+    document
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| explain_line(idx + 1, line, vocabulary))
+        .collect()
+}
+
+/// Finds every number on `line` and the two that make up its calibration
+/// value. Lines without any digits are explained rather than reported as an
+/// error, with nothing chosen.
+pub fn explain_line<'a>(number: usize, line: &'a str, vocabulary: &Vocabulary) -> Explanation<'a> {
+    let tokens: Vec<Token> = vocabulary.tokens(line).collect();
+    let chosen = tokens
+        .first()
+        .zip(tokens.last())
+        .map(|(first, last)| (first.clone(), last.clone()));
+
+    Explanation {
+        number,
+        line,
+        tokens,
+        chosen,
+    }
+}
+
+/// Prints the line, then a row per token with its byte span and value, and
+/// the value of the line:
+///
+/// ```text
+/// line 1: xtwone3
+///   two    1..4   2
+///   one    3..6   1
+///   3      6..7   3
+///   first two, last 3: 23
+/// ```
+impl fmt::Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.number, self.line)?;
+
+        for token in &self.tokens {
+            let span = format!("{}..{}", token.span.start, token.span.end);
+            write!(
+                f,
+                "\n  {:<6} {:<6} {}",
+                &self.line[token.span.clone()],
+                span,
+                token.value
+            )?;
+        }
+
+        match (&self.chosen, self.value()) {
+            (Some((first, last)), Some(value)) => write!(
+                f,
+                "\n  first {}, last {}: {}",
+                &self.line[first.span.clone()],
+                &self.line[last.span.clone()],
+                value
+            ),
+            _ => write!(f, "\n  no digits"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_line() {
+        let explanation = explain_line(1, "xtwone3", &Vocabulary::default());
+        let spans: Vec<_> = explanation.tokens.iter().map(|t| t.span.clone()).collect();
+        assert_eq!(spans, vec![1..4, 3..6, 6..7]);
+        assert_eq!(explanation.value(), Some(23));
+        assert_eq!(
+            explanation.to_string(),
+            "line 1: xtwone3\n  two    1..4   2\n  one    3..6   1\n  3      6..7   3\n  first two, last 3: 23"
+        );
+    }
+
+    #[test]
+    fn test_explain_line_without_digits() {
+        let explanation = explain_line(2, "abc", &Vocabulary::default());
+        assert_eq!(explanation.value(), None);
+        assert_eq!(explanation.to_string(), "line 2: abc\n  no digits");
+    }
+
+    #[test]
+    fn test_explain_document() {
+        let explanations = explain_document("1abc2\n\neight", &Vocabulary::default());
+        let values: Vec<_> = explanations.iter().map(Explanation::value).collect();
+        assert_eq!(values, vec![Some(12), None, Some(88)]);
+        assert_eq!(explanations[1].number, 2);
+    }
+}
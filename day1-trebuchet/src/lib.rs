@@ -0,0 +1,62 @@
+//! Day 1: Trebuchet?!
+//!
+//! Every line of the calibration document hides a value made up of the first
+//! and last digit on that line.
+
+use aoc_core::{Solution, SolveResult};
+
+pub mod explain;
+pub mod reader;
+pub mod scanner;
+pub mod trebuchet;
+pub mod vocabulary;
+
+pub use explain::{explain_document, explain_line, Explanation};
+#[cfg(feature = "rayon")]
+pub use reader::{sum_document_parallel, sum_reader_parallel, BATCH_SIZE};
+pub use reader::{sum_reader, ReadError};
+pub use scanner::{Scanner, Token};
+pub use trebuchet::{
+    calibration_value, parse_document, parse_line, sum_document, sum_document_digits, ParseError,
+    ParseErrorKind,
+};
+pub use vocabulary::{Language, Vocabulary};
+
+#[derive(Default)]
+pub struct Day1 {
+    /// Words counting as numbers in part 2, English by default.
+    pub vocabulary: Vocabulary,
+}
+
+impl Solution for Day1 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn name(&self) -> &'static str {
+        "trebuchet"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(sum_document(input, &self.vocabulary)?.into())
+    }
+}
+
+/// Sum of all calibration values, only counting numeric digits.
+pub fn part1(document: &str) -> Result<u64, ParseError> {
+    sum_document_digits(document)
+}
+
+/// Sum of all calibration values, also counting numbers spelled out in
+/// English.
+pub fn part2(document: &str) -> Result<u64, ParseError> {
+    sum_document(document, &Vocabulary::default())
+}
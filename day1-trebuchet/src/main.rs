@@ -1,20 +1,80 @@
-mod trebuchet;
+use std::path::Path;
+
+use aoc_core::cli::{for_each_file, read_input, Options};
+use aoc_core::Part;
+use clap::Parser;
+use trebuchet::{explain_document, Day1, Language, Vocabulary};
+
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    options: Options,
+
+    /// Language the numbers are spelled out in, in part 2
+    #[arg(long, value_enum, default_value_t = Language::English, conflicts_with = "check")]
+    language: Language,
+
+    /// TOML file of words and the numbers they stand for, used instead of a
+    /// built-in language
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["language", "check"])]
+    vocabulary: Option<String>,
+
+    /// Show the numbers found on every line and how they make up its value,
+    /// instead of solving
+    #[arg(long, conflicts_with_all = ["bench", "check", "format"])]
+    explain: bool,
+}
 
 fn main() {
-    // Open file passed in ARGV
-    let args: Vec<String> = std::env::args().collect();
-    // Print usage if no file is passed
-    if args.len() < 2 {
-        println!("Usage: trebuchet <filename>");
-        return;
+    let args: Args = aoc_core::cli::parse(&Day1::default());
+    let vocabulary = match &args.vocabulary {
+        Some(path) => Vocabulary::load(Path::new(path)).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }),
+        None => Vocabulary::builtin(args.language),
+    };
+
+    if args.explain {
+        explain(&args.options, vocabulary);
+    } else {
+        aoc_core::cli::run(&Day1 { vocabulary }, &args.options);
     }
-    
-    let filename = &args[1];
-    let document = std::fs::read_to_string(filename).expect("Something went wrong reading the file");
+}
+
+/// Explains every line of every file, only counting digits with `--part 1`.
+fn explain(options: &Options, vocabulary: Vocabulary) {
+    let vocabulary = match options.part {
+        Some(Part::One) => Vocabulary::new(vec![]),
+        _ => vocabulary,
+    };
 
-    // Sum document
-    let sum = trebuchet::sum_document(&document);
+    let failed = for_each_file(&options.files, true, |filename| {
+        explain_file(filename, &vocabulary)
+    });
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Prints the explanation of every line, and how many have no digits.
+fn explain_file(filename: &str, vocabulary: &Vocabulary) -> Result<(), String> {
+    let document = read_input(filename)?;
+
+    let explanations = explain_document(&document, vocabulary);
+    for explanation in &explanations {
+        println!("{}", explanation);
+    }
+
+    let without_digits = explanations.iter().filter(|e| e.value().is_none()).count();
+    if without_digits > 0 {
+        println!(
+            "{} of {} lines have no digits",
+            without_digits,
+            explanations.len()
+        );
+    }
 
-    // Print result
-    println!("{}", sum);
+    Ok(())
 }
@@ -0,0 +1,246 @@
+//! Summing calibration documents that are too large to read into memory at
+//! once.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::AddAssign;
+
+use crate::trebuchet::{parse_line, ParseError};
+use crate::vocabulary::Vocabulary;
+
+/// Failure to read a document, or a malformed line in it.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "could not read the document: {}", e),
+            ReadError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// Sums the calibration values of every line read from `reader`, holding a
+/// single line in memory at a time. The sum is a `T` of the caller's
+/// choosing, `u64` or `u128` for documents too large for [`sum_document`].
+///
+/// [`sum_document`]: crate::sum_document
+pub fn sum_reader<T, R>(mut reader: R, vocabulary: &Vocabulary) -> Result<T, ReadError>
+where
+    T: From<u32> + AddAssign + Default,
+    R: BufRead,
+{
+    let mut sum = T::default();
+    let mut buffer = String::new();
+    let mut lines = 0;
+
+    while reader.read_line(&mut buffer)? > 0 {
+        let line = strip_line_ending(&buffer);
+        sum += T::from(parse_line(line, vocabulary).map_err(|e| e.below(lines))?);
+
+        lines += 1;
+        buffer.clear();
+    }
+
+    Ok(sum)
+}
+
+// The line without its `\n` or `\r\n`, like `str::lines` yields it
+fn strip_line_ending(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+#[cfg(feature = "rayon")]
+pub use parallel::{sum_document_parallel, sum_reader_parallel, BATCH_SIZE};
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use std::io::BufRead;
+    use std::ops::AddAssign;
+
+    use rayon::prelude::*;
+
+    use super::ReadError;
+    use crate::trebuchet::{sum_lines, ParseError};
+    use crate::vocabulary::Vocabulary;
+
+    /// Bytes [`sum_reader_parallel`] reads before summing them.
+    pub const BATCH_SIZE: usize = 64 << 20;
+
+    /// Lines are handed to the thread pool in chunks of about this many bytes.
+    const CHUNK_SIZE: usize = 256 << 10;
+
+    /// Like [`super::sum_reader`], but reads `batch_size` bytes at a time and
+    /// sums each batch on rayon's thread pool. Memory use is bounded by the
+    /// batch size rather than the document.
+    pub fn sum_reader_parallel<T, R>(
+        mut reader: R,
+        vocabulary: &Vocabulary,
+        batch_size: usize,
+    ) -> Result<T, ReadError>
+    where
+        T: From<u32> + AddAssign + Default + Send,
+        R: BufRead,
+    {
+        let mut sum = T::default();
+        let mut batch = String::with_capacity(batch_size);
+        let mut lines = 0;
+
+        loop {
+            batch.clear();
+            while batch.len() < batch_size.max(1) && reader.read_line(&mut batch)? > 0 {}
+            if batch.is_empty() {
+                return Ok(sum);
+            }
+
+            sum += sum_chunks(&batch, vocabulary).map_err(|e| e.below(lines))?;
+            lines += batch.lines().count();
+        }
+    }
+
+    /// Sums the calibration values of `document` on rayon's thread pool.
+    pub fn sum_document_parallel<T>(
+        document: &str,
+        vocabulary: &Vocabulary,
+    ) -> Result<T, ParseError>
+    where
+        T: From<u32> + AddAssign + Default + Send,
+    {
+        sum_chunks(document, vocabulary)
+    }
+
+    // The sum of `document`, or the error on its first malformed line
+    fn sum_chunks<T>(document: &str, vocabulary: &Vocabulary) -> Result<T, ParseError>
+    where
+        T: From<u32> + AddAssign + Default + Send,
+    {
+        let chunks = chunks(document);
+        let sums: Vec<Result<T, ParseError>> = chunks
+            .par_iter()
+            .map(|chunk| sum_lines(chunk, vocabulary))
+            .collect();
+
+        let mut total = T::default();
+        for (idx, sum) in sums.into_iter().enumerate() {
+            // Errors are numbered within their chunk, so only then count the
+            // lines before it
+            let lines_before = || chunks[..idx].iter().map(|c| c.lines().count()).sum();
+            total += sum.map_err(|e| e.below(lines_before()))?;
+        }
+
+        Ok(total)
+    }
+
+    // Splits `document` into pieces of about `CHUNK_SIZE`, ending on a line
+    // ending so no line is split
+    fn chunks(document: &str) -> Vec<&str> {
+        let mut chunks = Vec::with_capacity(document.len() / CHUNK_SIZE + 1);
+        let mut rest = document;
+
+        while !rest.is_empty() {
+            let end = match rest.as_bytes().get(CHUNK_SIZE..) {
+                Some(tail) => tail
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .map_or(rest.len(), |idx| CHUNK_SIZE + idx + 1),
+                None => rest.len(),
+            };
+
+            let (chunk, tail) = rest.split_at(end);
+            chunks.push(chunk);
+            rest = tail;
+        }
+
+        chunks
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::ParseErrorKind;
+
+        #[test]
+        fn test_sum_document_parallel() {
+            let document = "a1b7cz\none2three\n".repeat(CHUNK_SIZE / 4);
+            let sum: u64 = sum_document_parallel(&document, &Vocabulary::default()).unwrap();
+            assert_eq!(sum, 30 * (CHUNK_SIZE / 4) as u64);
+
+            let document = format!("{}abc\n", "12\n".repeat(CHUNK_SIZE));
+            let error =
+                sum_document_parallel::<u64>(&document, &Vocabulary::default()).unwrap_err();
+            assert_eq!(
+                (error.kind, error.line),
+                (ParseErrorKind::NoDigits, CHUNK_SIZE + 1)
+            );
+        }
+
+        #[test]
+        fn test_sum_reader_parallel() {
+            let document = "1\n22\nabc\n";
+            let sum: u128 =
+                sum_reader_parallel("1\n22\n".as_bytes(), &Vocabulary::default(), 2).unwrap();
+            assert_eq!(sum, 33);
+
+            let error =
+                sum_reader_parallel::<u64, _>(document.as_bytes(), &Vocabulary::default(), 2)
+                    .unwrap_err();
+            assert!(matches!(error, ReadError::Parse(e) if e.line == 3));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseErrorKind;
+
+    #[test]
+    fn test_sum_reader() {
+        let document = "a1b7cz\r\none2three\n";
+        let sum: u64 = sum_reader(document.as_bytes(), &Vocabulary::default()).unwrap();
+        assert_eq!(sum, 30);
+
+        let sum: u128 = sum_reader("".as_bytes(), &Vocabulary::default()).unwrap();
+        assert_eq!(sum, 0);
+    }
+
+    #[test]
+    fn test_sum_reader_errors() {
+        let error = sum_reader::<u64, _>("1\n\n2".as_bytes(), &Vocabulary::default()).unwrap_err();
+        match error {
+            ReadError::Parse(e) => assert_eq!((e.kind, e.line), (ParseErrorKind::NoDigits, 2)),
+            ReadError::Io(e) => panic!("unexpected {}", e),
+        }
+
+        let error =
+            sum_reader::<u64, _>(&[b'1', 0xff, b'\n'][..], &Vocabulary::default()).unwrap_err();
+        assert!(matches!(error, ReadError::Io(_)));
+    }
+}
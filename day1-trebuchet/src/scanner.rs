@@ -0,0 +1,108 @@
+use std::ops::Range;
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, Anchored, Input, MatchKind, StartKind};
+
+/// A number found on a line, and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub value: u32,
+    /// Byte range of the token in the line.
+    pub span: Range<usize>,
+}
+
+/// Finds the first and last of a set of tokens on a line, without looking at
+/// anything in between.
+///
+/// The first token is found with a single unanchored search from the start of
+/// the line. The last one is found walking back from the end, trying an
+/// anchored match at every offset, so each step only follows the automaton as
+/// far as the longest token. Tokens may overlap: in `eighthree` the first
+/// token is `eight` and the last one is `three`.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl Scanner {
+    /// A scanner for the given tokens and the values they stand for. When
+    /// several tokens start at the same offset, the longest one wins.
+    pub fn new<'a>(tokens: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let (patterns, values): (Vec<&str>, Vec<u32>) = tokens.into_iter().unzip();
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostLongest)
+            .start_kind(StartKind::Both)
+            .build(patterns)
+            .expect("number tokens fit in an automaton");
+
+        Self { automaton, values }
+    }
+
+    /// The token starting closest to the start of the line.
+    pub fn first(&self, line: &str) -> Option<Token> {
+        let found = self.automaton.find(line)?;
+
+        Some(Token {
+            value: self.values[found.pattern()],
+            span: found.range(),
+        })
+    }
+
+    /// The token starting closest to the end of the line.
+    pub fn last(&self, line: &str) -> Option<Token> {
+        (0..line.len())
+            .rev()
+            .find_map(|start| self.token_at(line, start))
+    }
+
+    /// Every token on the line, including overlapping ones, by where they
+    /// start. This has to try every offset, so prefer [`Scanner::first`] and
+    /// [`Scanner::last`] when those are all that's needed.
+    pub fn tokens<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Token> + 'a {
+        (0..line.len()).filter_map(move |start| self.token_at(line, start))
+    }
+
+    /// The longest token starting at byte `start`, if any.
+    fn token_at(&self, line: &str, start: usize) -> Option<Token> {
+        let input = Input::new(line).range(start..).anchored(Anchored::Yes);
+        let found = self.automaton.find(input)?;
+
+        Some(Token {
+            value: self.values[found.pattern()],
+            span: found.range(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scanner() -> Scanner {
+        Scanner::new([("1", 1), ("2", 2), ("one", 1), ("two", 2), ("twone", 21)])
+    }
+
+    #[test]
+    fn test_first_and_last() {
+        let scanner = scanner();
+        let token = scanner.first("xone2").unwrap();
+        assert_eq!((token.value, token.span), (1, 1..4));
+
+        let token = scanner.last("xone2").unwrap();
+        assert_eq!((token.value, token.span), (2, 4..5));
+
+        assert_eq!(scanner.first("abc"), None);
+        assert_eq!(scanner.last(""), None);
+    }
+
+    #[test]
+    fn test_overlapping_tokens() {
+        let scanner = scanner();
+        assert_eq!(scanner.first("xtwone").unwrap().value, 21);
+        assert_eq!(scanner.last("twonex").unwrap().value, 1);
+        assert_eq!(scanner.last("oneoneone").unwrap().span, 6..9);
+
+        let tokens: Vec<u32> = scanner.tokens("twone2").map(|token| token.value).collect();
+        assert_eq!(tokens, vec![21, 1, 2]);
+    }
+}
@@ -1,109 +1,148 @@
-use std::collections::HashMap;
-use lazy_static::lazy_static;
-use regex::Regex;
-
-lazy_static! {
-  static ref NUMBER_WORDS: HashMap<&'static str, &'static str> = {
-    let numbers = [
-        ("one", "1"), ("two", "2"), ("three", "3"), ("four", "4"),
-        ("five", "5"), ("six", "6"), ("seven", "7"), ("eight", "8"), ("nine", "9"),
-    ];
-    numbers.iter().cloned().collect()
-  };
-
-  static ref NUMBER_REGEX: Regex = Regex::new(
-    r"\A(\d|one|two|three|four|five|six|seven|eight|nine)"
-  ).unwrap();
-}
-
-pub fn sum_document(document: &str) -> i32 {
-  let numbers = parse_document(document);
-  let mut sum = 0;
+use std::fmt;
+use std::ops::AddAssign;
 
-  for number in numbers {
-    sum += number;
-  }
+use crate::scanner::Token;
+use crate::vocabulary::Vocabulary;
 
-  return sum;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+  NoDigits,
 }
 
-fn parse_document(document: &str) -> Vec<i32> {
-  let mut numbers = Vec::new();
-
-  for line in document.lines() {
-    let number = parse_line(line);
-    // Show your work
-    numbers.push(number);
+impl fmt::Display for ParseErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParseErrorKind::NoDigits => write!(f, "no digits on line"),
+    }
   }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
 
-  return numbers;
+/// Sums the calibration values of all lines, with numbers spelled out as
+/// words of `vocabulary` counting as digits.
+pub fn sum_document(document: &str, vocabulary: &Vocabulary) -> Result<u64, ParseError> {
+  sum_lines(document, vocabulary)
 }
 
-fn detect_numbers(line: &str) -> Vec<&str> {
-  let mut idx = 0;
-  let mut result = Vec::new();
-
-  while idx < line.len() {
-    match NUMBER_REGEX.captures(&line[idx..]) {
-      Some(captures) => {
-        let word = captures.get(0).unwrap().as_str();
-        let number = words_to_number(word).unwrap();
-        result.push(number);
-      },
-      None => {}
-    }
-    idx += 1;
+/// Sums the calibration values of all lines into a `T` of the caller's
+/// choosing, without collecting them first.
+pub(crate) fn sum_lines<T>(document: &str, vocabulary: &Vocabulary) -> Result<T, ParseError>
+where
+  T: From<u32> + AddAssign + Default,
+{
+  let mut sum = T::default();
+
+  for (idx, line) in document.lines().enumerate() {
+    sum += T::from(parse_line(line, vocabulary).map_err(|e| e.below(idx))?);
   }
 
-  result
+  Ok(sum)
 }
 
-fn parse_line(line: &str) -> i32 {
-  let numbers_in_string = detect_numbers(line);
+/// Sums the calibration values of all lines, only considering actual digits.
+pub fn sum_document_digits(document: &str) -> Result<u64, ParseError> {
+  let numbers = aoc_core::parse_lines(document, |line| {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    let first = digits
+      .next()
+      .ok_or_else(|| ParseError::new(ParseErrorKind::NoDigits, line, line))?;
+    let last = digits.next_back().unwrap_or(first);
+    Ok(u64::from(first * 10 + last))
+  })?;
+
+  Ok(numbers.into_iter().sum())
+}
 
-  let &first_number = numbers_in_string.first().unwrap();
-  let &last_number = numbers_in_string.last().unwrap_or(&first_number);
+/// The calibration value of every line in the document.
+pub fn parse_document(document: &str, vocabulary: &Vocabulary) -> Result<Vec<u32>, ParseError> {
+  aoc_core::parse_lines(document, |line| parse_line(line, vocabulary))
+}
 
-  let mut full_number = String::new();
-  full_number.push_str(first_number);
-  full_number.push_str(last_number);
+/// The calibration value of a single line: its first and last number
+/// combined into a two-digit number.
+pub fn parse_line(line: &str, vocabulary: &Vocabulary) -> Result<u32, ParseError> {
+  let first = vocabulary
+    .first(line)
+    .ok_or_else(|| ParseError::new(ParseErrorKind::NoDigits, line, line))?;
+  let last = vocabulary.last(line).unwrap_or_else(|| first.clone());
 
-  let number: i32 = full_number.parse().unwrap();
+  Ok(calibration_value(&first, &last))
+}
 
-  return number;
+/// Combines the first and last number on a line. Numbers of several digits,
+/// like `ten`, contribute their leading digit when first and their last digit
+/// when last.
+pub fn calibration_value(first: &Token, last: &Token) -> u32 {
+  leading_digit(first.value) * 10 + last.value % 10
 }
 
-fn words_to_number(word: &str) -> Option<&str> {
-  if word.chars().all(char::is_numeric) {
-    return Some(word);
-  } else {
-    return NUMBER_WORDS.get(word).copied();
+fn leading_digit(mut number: u32) -> u32 {
+  while number >= 10 {
+    number /= 10;
   }
+
+  number
 }
 
 // Test
+#[cfg(test)]
 mod tests {
+    use crate::vocabulary::{Language, Vocabulary};
+
     #[test]
     fn test_parse_line() {
-        assert_eq!(super::parse_line("a1b7cz"), 17);
-        assert_eq!(super::parse_line("a1b"), 11);
+        assert_eq!(super::parse_line("a1b7cz", &Vocabulary::default()), Ok(17));
+        assert_eq!(super::parse_line("a1b", &Vocabulary::default()), Ok(11));
     }
 
     #[test]
     fn test_parse_line_also_detects_numbers_as_words() {
-      assert_eq!(super::parse_line("aone2b7cz"), 17);
-      assert_eq!(super::parse_line("eighthree"), 83);
+      assert_eq!(super::parse_line("aone2b7cz", &Vocabulary::default()), Ok(17));
+      assert_eq!(super::parse_line("eighthree", &Vocabulary::default()), Ok(83));
+      assert_eq!(super::parse_line("7sevenine", &Vocabulary::default()), Ok(79));
+    }
+
+    #[test]
+    fn test_parse_line_with_vocabulary() {
+      let french = Vocabulary::builtin(Language::French);
+      assert_eq!(super::parse_line("deuxtroisx", &french), Ok(23));
+
+      let vocabulary = Vocabulary::from_toml("zero = 0\nten = 10\ntwelve = 12").unwrap();
+      assert_eq!(super::parse_line("tenxzero", &vocabulary), Ok(10));
+      assert_eq!(super::parse_line("zero3twelve", &vocabulary), Ok(2));
+      assert_eq!(super::parse_line("twelve", &vocabulary), Ok(12));
+    }
+
+    #[test]
+    fn test_parse_line_without_digits() {
+      let error = super::parse_line("abc", &Vocabulary::default()).unwrap_err();
+      assert_eq!(error.kind, super::ParseErrorKind::NoDigits);
+      assert_eq!(error.text, "abc");
     }
 
     #[test]
     fn test_parse_document() {
         let document = "a1b7cz\na1b";
-        assert_eq!(super::parse_document(document), vec![17, 11]);
+        assert_eq!(super::parse_document(document, &Vocabulary::default()), Ok(vec![17, 11]));
     }
 
     #[test]
     fn test_sum_document() {
         let document = "a1b7cz\na1b";
-        assert_eq!(super::sum_document(document), 28);
+        assert_eq!(super::sum_document(document, &Vocabulary::default()), Ok(28));
+    }
+
+    #[test]
+    fn test_sum_document_digits() {
+        let document = "a1b7cz\none2three";
+        assert_eq!(super::sum_document_digits(document), Ok(39));
+    }
+
+    #[test]
+    fn test_sum_document_digits_reports_line() {
+        let error = super::sum_document_digits("a1b\ntwo").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.to_string(), "line 2, column 1: no digits on line \"two\"");
     }
 }
\ No newline at end of file
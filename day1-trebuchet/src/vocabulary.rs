@@ -0,0 +1,152 @@
+use std::path::Path;
+
+use crate::scanner::{Scanner, Token};
+
+/// Languages with a built-in vocabulary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Language {
+    #[default]
+    English,
+    Dutch,
+    French,
+    German,
+}
+
+impl Language {
+    /// The words for one to nine, which are the only ones the puzzle spells
+    /// out.
+    pub fn words(&self) -> [&'static str; 9] {
+        match self {
+            Language::English => [
+                "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+            ],
+            Language::Dutch => [
+                "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
+            ],
+            Language::French => [
+                "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+            ],
+            Language::German => [
+                "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+            ],
+        }
+    }
+}
+
+/// The words a calibration document may spell numbers out as, on top of the
+/// digits `0` to `9`.
+///
+/// A word can stand for any number, e.g. `ten` for 10. It then counts as its
+/// leading digit when it's the first number on a line, and as its last digit
+/// when it's the last one.
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    scanner: Scanner,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::builtin(Language::default())
+    }
+}
+
+impl Vocabulary {
+    pub fn new(words: Vec<(String, u32)>) -> Self {
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        let tokens = digits
+            .into_iter()
+            .zip(0..)
+            .chain(words.iter().map(|(word, value)| (word.as_str(), *value)));
+        let scanner = Scanner::new(tokens);
+
+        Self { words, scanner }
+    }
+
+    pub fn builtin(language: Language) -> Self {
+        let words = language.words().into_iter().map(str::to_string).zip(1..);
+
+        Self::new(words.collect())
+    }
+
+    /// Parses a vocabulary from a TOML table of words and the numbers they
+    /// stand for:
+    ///
+    /// ```toml
+    /// zero = 0
+    /// one = 1
+    /// ten = 10
+    /// ```
+    pub fn from_toml(contents: &str) -> Result<Self, String> {
+        let table: toml::Table = contents
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+        let words = table
+            .into_iter()
+            .map(|(word, value)| match value {
+                _ if word.is_empty() => Err("words can't be empty".to_string()),
+                toml::Value::Integer(n) => u32::try_from(n)
+                    .map(|n| (word.clone(), n))
+                    .map_err(|_| format!("{} should be a number from 0 to {}", word, u32::MAX)),
+                _ => Err(format!("{} should be a number", word)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::new(words))
+    }
+
+    /// Loads a vocabulary from a TOML file, see [`Vocabulary::from_toml`].
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        Self::from_toml(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// The spelled out words, without the digits.
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    /// The number starting closest to the start of `line`.
+    pub fn first(&self, line: &str) -> Option<Token> {
+        self.scanner.first(line)
+    }
+
+    /// The number starting closest to the end of `line`.
+    pub fn last(&self, line: &str) -> Option<Token> {
+        self.scanner.last(line)
+    }
+
+    /// Every number on `line`, see [`Scanner::tokens`].
+    pub fn tokens<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Token> + 'a {
+        self.scanner.tokens(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin() {
+        let dutch = Vocabulary::builtin(Language::Dutch);
+        assert_eq!(dutch.first("xzevenacht").unwrap().value, 7);
+        assert_eq!(dutch.last("xzevenacht").unwrap().value, 8);
+
+        let german = Vocabulary::builtin(Language::German);
+        assert_eq!(german.last("zweifünfx").unwrap().span, 4..9);
+    }
+
+    #[test]
+    fn test_from_toml() {
+        let vocabulary = Vocabulary::from_toml("zero = 0\nten = 10\n").unwrap();
+        assert_eq!(vocabulary.first("xzero1").unwrap().value, 0);
+        assert_eq!(vocabulary.last("xzero1ten").unwrap().value, 10);
+
+        assert!(Vocabulary::from_toml("one = \"1\"").is_err());
+        assert!(Vocabulary::from_toml("one = -1").is_err());
+        assert!(Vocabulary::from_toml("\"\" = 1").is_err());
+    }
+}
@@ -0,0 +1,13 @@
+use aoc_core::{Answer, Solution};
+use trebuchet::{Day1, ParseErrorKind};
+
+// The example is the one from part 2, whose spelled out digits part 1 can't read
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    let error = trebuchet::part1(EXAMPLE).unwrap_err();
+    assert_eq!((error.kind, error.line), (ParseErrorKind::NoDigits, 2));
+
+    assert_eq!(Day1::default().part2(EXAMPLE).unwrap(), Answer::Number(281));
+}
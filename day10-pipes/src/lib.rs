@@ -0,0 +1,312 @@
+//! Day 10: Pipe Maze
+//!
+//! Follows the loop of pipes the animal is hiding in, measuring its length
+//! and the area it encloses.
+
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+use aoc_grid::{Grid, Point};
+
+pub use aoc_grid::Direction;
+
+pub type Map = Grid<Piece>;
+
+/// The field of pipes, with the position of the animal (`S`).
+///
+/// `area_size` and `path_size` are only filled in once `build_path` has run.
+#[derive(Debug)]
+pub struct Maze {
+    pub map: Map,
+    pub animal: Point,
+    pub area_size: usize,
+    pub path_size: usize
+}
+
+/// A single tile of the maze and the directions its pipe connects to.
+#[derive(Debug)]
+pub struct Piece {
+    pub directions: Vec<Direction>,
+    pub is_animal: bool
+}
+
+impl Piece {
+    pub fn empty() -> Self {
+        Self {
+            directions: vec![],
+            is_animal: false,
+        }
+    }
+
+    /// The piece drawn as `c`, or `None` if it isn't a known tile.
+    pub fn from_char(c: char) -> Option<Self> {
+        let piece = match c {
+            '|' => Self {
+                directions: vec![Direction::North, Direction::South],
+                ..Self::empty()
+            },
+            '-' => Self {
+                directions: vec![Direction::East, Direction::West],
+                ..Self::empty()
+            },
+            'L' => Self {
+                directions: vec![Direction::North, Direction::East],
+                ..Self::empty()
+            },
+            'J' => Self {
+                directions: vec![Direction::North, Direction::West],
+                ..Self::empty()
+            },
+            '7' => Self {
+                directions: vec![Direction::South, Direction::West],
+                ..Self::empty()
+            },
+            'F' => Self {
+                directions: vec![Direction::South, Direction::East],
+                ..Self::empty()
+            },
+            'S' => Self {
+                is_animal: true,
+                ..Self::empty()
+            },
+            '.' => Self::empty(),
+            _ => return None,
+        };
+
+        Some(piece)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidTile,
+    RaggedLine,
+    MissingAnimal,
+    AnimalNotOnLoop,
+    BrokenLoop,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidTile => write!(f, "not a pipe or ground tile"),
+            ParseErrorKind::RaggedLine => write!(f, "line length differs from the first line"),
+            ParseErrorKind::MissingAnimal => write!(f, "no animal (S) in the maze"),
+            ParseErrorKind::AnimalNotOnLoop => write!(f, "fewer than two pipes connect to the animal"),
+            ParseErrorKind::BrokenLoop => write!(f, "pipe doesn't connect back to the loop"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+impl FromStr for Maze {
+    type Err = ParseError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(doc, ParseErrorKind::RaggedLine, |c| {
+            Piece::from_char(c).ok_or(ParseErrorKind::InvalidTile)
+        })?;
+        let animal = map
+            .iter()
+            .find(|(_, piece)| piece.is_animal)
+            .map(|(position, _)| position);
+
+        let animal = animal.ok_or_else(|| ParseError::new(ParseErrorKind::MissingAnimal, doc, ""))?;
+
+        Ok(Self { map, animal, area_size: 0, path_size: 0 })
+    }
+}
+
+impl Maze {
+    pub fn at(&self, position: Point) -> Option<&Piece> {
+        self.map.get(position)
+    }
+
+    /// Steps one tile in `direction`, or `None` when that leaves the maze.
+    pub fn go(&self, position: Point, direction: Direction) -> Option<Point> {
+        self.map.step(position, direction)
+    }
+
+    /// Follows the loop starting at the animal, recording its length and the
+    /// area it encloses.
+    ///
+    /// Fails if the animal isn't between two pipes, or if the loop leaves the
+    /// maze or runs into a pipe that doesn't lead back the way it came.
+    pub fn build_path(&mut self) -> Result<(), ParseError> {
+        let mut path = vec![];
+        // Check pieces around the animal
+        let animal_directions: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter_map(|direction| {
+                let pos = self.go(self.animal, direction)?;
+                let piece = self.at(pos)?;
+
+                if piece.directions.contains(&direction.opposite()) {
+                    Some(direction)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        if animal_directions.len() < 2 {
+            return Err(error_at(ParseErrorKind::AnimalNotOnLoop, self.animal));
+        }
+
+        self.map[self.animal].directions = animal_directions.clone();
+
+        // Start the path
+        let mut direction = animal_directions[0];
+        let mut position = self.go(self.animal, direction).unwrap();
+        path.push(self.animal);
+        let mut steps = 1;
+
+        while position != self.animal {
+            let broken = || error_at(ParseErrorKind::BrokenLoop, position);
+            let piece = self.at(position).ok_or_else(broken)?;
+            if !piece.directions.contains(&direction.opposite()) {
+                return Err(broken());
+            }
+            let next_direction = piece
+                .directions
+                .iter()
+                .find(|dir| **dir != direction.opposite())
+                .ok_or_else(broken)?;
+
+            path.push(position);
+            direction = *next_direction;
+            position = self.go(position, direction).ok_or_else(broken)?;
+            steps += 1;
+        }
+
+        self.area_size = self.calculate_area_size(&path) as usize;
+        self.path_size = steps;
+
+        Ok(())
+    }
+
+    fn calculate_area_size(&self, path: &[Point]) -> i64 {
+        let mut area_size = 0;
+        let len = path.len();
+
+        for i in 0..len {
+            let p1 = path[i];
+            let p2 = path[(i + 1) % len];
+
+            area_size += (p1.0 as i64 - p2.0 as i64) * (p1.1 as i64 + p2.1 as i64);
+        }
+
+        area_size.abs() / 2
+    }
+}
+
+// An error about the tile at `position`, which has no text of its own once
+// the maze is parsed
+fn error_at(kind: ParseErrorKind, (row, column): Point) -> ParseError {
+    ParseError { kind, line: row + 1, column: column + 1, text: String::new() }
+}
+
+/// Parses the maze and traces the loop through it.
+pub fn parse_document(document: &str) -> Result<Maze, ParseError> {
+    let mut maze = Maze::from_str(document)?;
+    maze.build_path()?;
+
+    Ok(maze)
+}
+
+/// Number of steps to the point of the loop farthest from the animal.
+pub fn part1(maze: &Maze) -> usize {
+    maze.path_size / 2
+}
+
+/// Number of tiles enclosed by the loop, using Pick's theorem.
+pub fn part2(maze: &Maze) -> usize {
+    maze.area_size - maze.path_size / 2 + 1
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn name(&self) -> &'static str {
+        "pipes"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_document(input)?).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&parse_document(input)?).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(parse_document(input)?, part1, part2)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MAZE: &str = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+    #[test]
+    fn test_parse_maze() {
+        let maze = Maze::from_str(MAZE).unwrap();
+
+        assert_eq!(maze.map.height(), 5);
+        assert_eq!(maze.map.width(), 5);
+
+        assert_eq!(maze.animal, (2, 0));
+    }
+
+    #[test]
+    fn test_parse_maze_errors() {
+        let error = Maze::from_str("7-F7-\n.FJ|7\nSJxL7").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidTile, 3, 3));
+
+        let error = Maze::from_str("7-F7-\n.FJ|\nSJLL7").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::RaggedLine, 2));
+
+        let error = Maze::from_str("7-F7-\n.FJ|7").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingAnimal);
+    }
+
+    #[test]
+    fn test_build_path() {
+        let maze = parse_document(MAZE).unwrap();
+        assert_eq!((part1(&maze), part2(&maze)), (8, 1));
+    }
+
+    #[test]
+    fn test_build_path_errors() {
+        let error = parse_document("S.\n..").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::AnimalNotOnLoop, 1, 1));
+
+        // Only one pipe leads into the animal
+        let error = parse_document("S-7\n..|\n...").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::AnimalNotOnLoop);
+
+        // The pipe under the 7 points the wrong way
+        let error = parse_document("S-7\n|.-\nL-J").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::BrokenLoop, 2, 3));
+
+        // The loop runs off the edge of the maze
+        let error = parse_document("S7\nL|").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::BrokenLoop, 2, 2));
+    }
+}
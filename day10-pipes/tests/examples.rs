@@ -0,0 +1,17 @@
+use aoc_core::{Answer, Solution};
+use day10_pipes::Day10;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+const EXAMPLE2: &str = include_str!("../example2.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day10.part1(EXAMPLE).unwrap(), Answer::Number(8));
+    assert_eq!(Day10.part2(EXAMPLE).unwrap(), Answer::Number(1));
+}
+
+#[test]
+fn test_example2() {
+    assert_eq!(Day10.part1(EXAMPLE2).unwrap(), Answer::Number(70));
+    assert_eq!(Day10.part2(EXAMPLE2).unwrap(), Answer::Number(8));
+}
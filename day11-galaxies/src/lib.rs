@@ -0,0 +1,274 @@
+//! Day 11: Cosmic Expansion
+//!
+//! Sums the distances between every pair of galaxies in an image of a
+//! universe whose empty rows and columns keep expanding.
+
+use std::fmt;
+
+use aoc_core::{Solution, SolveResult};
+use aoc_grid::Grid;
+
+/// Sum of distances once every empty row and column is twice as big.
+pub fn part1(document: &str) -> Result<usize, ParseError> {
+    sum_of_distances(document, 2)
+}
+
+/// Sum of distances once every empty row and column is a million times as big.
+pub fn part2(document: &str) -> Result<usize, ParseError> {
+    sum_of_distances(document, EXPANSION)
+}
+
+/// How many times bigger every empty row and column is in part 2.
+pub const EXPANSION: usize = 1_000_000;
+
+pub struct Day11 {
+    /// Expansion of empty rows and columns in part 2, see [`EXPANSION`].
+    pub expansion: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            expansion: EXPANSION,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn name(&self) -> &'static str {
+        "galaxies"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(sum_of_distances(input, self.expansion)?.into())
+    }
+}
+
+/// Sum of the shortest paths between all galaxy pairs, with every empty row
+/// and column counting as `expansion` steps.
+pub fn sum_of_distances(document: &str, expansion: usize) -> Result<usize, ParseError> {
+    let universe = Universe::new(document, expansion)?;
+
+    let galaxy_pairs = combine_galaxies(&universe.galaxies);
+
+    Ok(galaxy_pairs
+        .into_iter()
+        .map(|(g1, g2)| universe.distance_to(g1, g2))
+        .sum())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidCharacter,
+    RaggedLine,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidCharacter => write!(f, "expected . or #"),
+            ParseErrorKind::RaggedLine => write!(f, "line length differs from the first line"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+const BLANK_SPACE: char = '.';
+const GALAXY: char = '#';
+
+/// What a single position in the image contains.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Space {
+    Nothing,
+    ExpandedNothing,
+    Galaxy,
+}
+
+impl From<&char> for Space {
+    fn from(c: &char) -> Space {
+        match *c {
+            GALAXY => Space::Galaxy,
+            _ => Space::Nothing,
+        }
+    }
+}
+
+pub type Map = Grid<Space>;
+
+/// The observed image with its empty rows and columns marked as expanded.
+#[derive(Debug)]
+pub struct Universe {
+    pub map: Map,
+    pub galaxies: Vec<Galaxy>,
+    pub expansion: usize,
+}
+
+impl Universe {
+    pub fn new(document: &str, expansion: usize) -> Result<Self, ParseError> {
+        let map = expand_map(document)?;
+        let galaxies = map_galaxies(&map);
+
+        Ok(Self { map, galaxies, expansion })
+    }
+
+    /// Length of the shortest path between two galaxies.
+    pub fn distance_to(&self, a: &Galaxy, b: &Galaxy) -> usize {
+        let mut position = a.position;
+        let mut distance_traveled = 0;
+
+        while position != b.position {
+            let (x, y) = position;
+
+            if x < b.position.0 {
+                position.0 += 1;
+            } else if x > b.position.0 {
+                position.0 -= 1;
+            } else if y < b.position.1 {
+                position.1 += 1;
+            } else if y > b.position.1 {
+                position.1 -= 1;
+            }
+
+            let step_size = match self.map[(position.0 as usize, position.1 as usize)] {
+                Space::ExpandedNothing => self.expansion,
+                _ => 1
+            };
+            distance_traveled += step_size;
+        }
+
+        distance_traveled
+    }
+}
+
+#[derive(Debug)]
+pub struct Galaxy {
+    pub position: (i32, i32),
+}
+
+/// Parses the image, marking rows and columns without galaxies as expanded.
+pub fn expand_map(document: &str) -> Result<Map, ParseError> {
+    let mut map = Grid::parse(document, ParseErrorKind::RaggedLine, |c| match c {
+        BLANK_SPACE | GALAXY => Ok(Space::from(&c)),
+        _ => Err(ParseErrorKind::InvalidCharacter),
+    })?;
+
+    let empty_rows: Vec<usize> = (0..map.height())
+        .filter(|&row| !map.row(row).contains(&Space::Galaxy))
+        .collect();
+    let empty_columns: Vec<usize> = (0..map.width())
+        .filter(|&column| map.column(column).all(|space| *space != Space::Galaxy))
+        .collect();
+
+    for row in empty_rows {
+        for column in 0..map.width() {
+            map[(row, column)] = Space::ExpandedNothing;
+        }
+    }
+    for column in empty_columns {
+        for row in 0..map.height() {
+            map[(row, column)] = Space::ExpandedNothing;
+        }
+    }
+
+    Ok(map)
+}
+
+pub fn map_galaxies(map: &Map) -> Vec<Galaxy> {
+    let mut galaxies = Vec::new();
+
+    for ((i, j), space) in map.iter() {
+        if *space == Space::Galaxy {
+            galaxies.push(Galaxy {
+                position: (i as i32, j as i32),
+            });
+        }
+    }
+
+    galaxies
+}
+
+/// Every unordered pair of galaxies.
+pub fn combine_galaxies(galaxies: &[Galaxy]) -> Vec<(&Galaxy, &Galaxy)> {
+    let mut galaxy_pairs = Vec::new();
+
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            galaxy_pairs.push((&galaxies[i], &galaxies[j]));
+        }
+    }
+
+    galaxy_pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_map() {
+        let input = ".#..
+....
+...#";
+        let expected = [
+            vec![
+                Space::ExpandedNothing,
+                Space::Galaxy,
+                Space::ExpandedNothing,
+                Space::Nothing,
+            ],
+            vec![
+                Space::ExpandedNothing,
+                Space::ExpandedNothing,
+                Space::ExpandedNothing,
+                Space::ExpandedNothing,
+            ],
+            vec![
+                Space::ExpandedNothing,
+                Space::Nothing,
+                Space::ExpandedNothing,
+                Space::Galaxy,
+            ],
+        ];
+        assert_eq!(expand_map(input), Ok(Grid::from_cells(4, expected.concat())));
+    }
+
+    #[test]
+    fn test_expand_map_errors() {
+        let error = expand_map(".#..\n..x.").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidCharacter, 2, 3));
+
+        let error = expand_map(".#..\n...").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::RaggedLine, 2));
+    }
+
+    #[test]
+    fn test_sum_of_distances() {
+        let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        assert_eq!(sum_of_distances(input, 2), Ok(374));
+        assert_eq!(sum_of_distances(input, 10), Ok(1030));
+        assert_eq!(sum_of_distances(input, 100), Ok(8410));
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_core::{Answer, Solution};
+use day11_galaxies::Day11;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(
+        Day11::default().part1(EXAMPLE).unwrap(),
+        Answer::Number(374)
+    );
+    assert_eq!(
+        Day11::default().part2(EXAMPLE).unwrap(),
+        Answer::Number(82000210)
+    );
+}
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day12_springs::{parse_document, valid_options_for};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+fn bench_valid_options_for(c: &mut Criterion) {
+    let rows = parse_document(EXAMPLE).unwrap();
+
+    for (name, unfold) in [("valid_options_for", 1), ("valid_options_for unfolded", 5)] {
+        let rows: Vec<_> = rows.iter().map(|row| row.unfold(unfold)).collect();
+
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                rows.iter()
+                    .map(|row| {
+                        let mut cache = HashMap::new();
+                        valid_options_for(
+                            black_box(row.pattern.clone()),
+                            black_box(row.expected_sizes.clone()),
+                            &mut cache,
+                        )
+                    })
+                    .sum::<u128>()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_valid_options_for);
+criterion_main!(benches);
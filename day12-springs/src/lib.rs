@@ -0,0 +1,319 @@
+//! Day 12: Hot Springs
+//!
+//! Counts the arrangements of operational and damaged springs that match
+//! the damaged group sizes of each row.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+use rayon::prelude::*;
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl std::fmt::Debug for Spring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Spring::Operational => write!(f, "."),
+            Spring::Damaged => write!(f, "#"),
+            Spring::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+impl TryFrom<char> for Spring {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            _ => {
+                let text = c.to_string();
+                Err(ParseError::new(ParseErrorKind::InvalidSpring, &text, &text))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidSpring,
+    MissingSizes,
+    InvalidSize,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidSpring => write!(f, "springs are ., # or ?"),
+            ParseErrorKind::MissingSizes => write!(f, "expected \"<springs> <sizes>\""),
+            ParseErrorKind::InvalidSize => write!(f, "invalid group size"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// A row of the condition records: the springs and the sizes of the
+/// contiguous groups of damaged springs.
+#[derive(Debug, PartialEq)]
+pub struct Row {
+    pub pattern: Vec<Spring>,
+    pub expected_sizes: Vec<usize>,
+}
+
+impl FromStr for Row {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (pattern, expected_sizes) = str
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingSizes, str, str))?;
+
+        let pattern: Vec<Spring> = pattern
+            .char_indices()
+            .map(|(idx, c)| Spring::try_from(c).map_err(|e| e.within(str, &str[idx..])))
+            .collect::<Result<_, _>>()?;
+        let expected_sizes: Vec<usize> = expected_sizes
+            .split(',')
+            .map(|s| {
+                s.parse()
+                    .map_err(|_| ParseError::new(ParseErrorKind::InvalidSize, str, s))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            pattern,
+            expected_sizes,
+        })
+    }
+}
+
+/// Number of ways the unknown springs in `pattern` can be filled in to
+/// produce `expected_sizes`, memoized in `cache`.
+pub fn valid_options_for(pattern: Vec<Spring>, expected_sizes: Vec<usize>, cache: &mut HashMap<(Vec<Spring>, Vec<usize>), u128>) -> u128 {
+    if let Some(result) = cache.get(&(pattern.clone(), expected_sizes.clone())) {
+        return *result;
+    }
+
+    let mut valid_options = 0;
+
+    let mut pattern = pattern;
+    let mut expected_sizes = expected_sizes;
+
+    // Find the first unknown or operational spring index
+    let first_unknown_spring_index =
+        pattern.iter().position(|spring| *spring != Spring::Damaged);
+
+    match first_unknown_spring_index {
+        Some(index) if pattern[index] == Spring::Operational => {
+            let part = &pattern[..=index];
+            match part {
+                [.., Spring::Damaged, Spring::Operational] => {
+                    let damaged_springs = part
+                        .iter()
+                        .filter(|spring| **spring == Spring::Damaged)
+                        .count();
+                    if expected_sizes.is_empty() {
+                        // All good
+                    } else if damaged_springs == expected_sizes[0] {
+                        // We can consume a part
+                        expected_sizes.remove(0);
+                        pattern = pattern[index + 1..].to_vec();
+
+                        valid_options +=
+                            valid_options_for(pattern.clone(), expected_sizes.clone(), cache);
+                    } else {
+                        // All good
+                    }
+                }
+                _ => {
+                    pattern = pattern[index + 1..].to_vec();
+                    valid_options +=
+                        valid_options_for(pattern.clone(), expected_sizes.clone(), cache);
+                }
+            }
+        }
+        Some(index) => {
+            pattern[index] = Spring::Operational;
+            valid_options += valid_options_for(pattern.clone(), expected_sizes.clone(), cache);
+
+            pattern[index] = Spring::Damaged;
+            valid_options += valid_options_for(pattern.clone(), expected_sizes.clone(), cache);
+        }
+        None => {
+            let damaged_spring_count = pattern
+            .iter()
+            .filter(|&spring| *spring == Spring::Damaged)
+            .count();
+
+            if (expected_sizes.len() == 1 && expected_sizes[0] == damaged_spring_count)
+                || (expected_sizes.is_empty() && damaged_spring_count == 0)
+            {
+                valid_options += 1;
+            }
+        }
+    }
+
+    cache.insert((pattern, expected_sizes), valid_options);
+    valid_options
+}
+
+impl Row {
+    /// The row repeated `times` times, with the copies of the pattern joined
+    /// by an unknown spring.
+    pub fn unfold(&self, times: usize) -> Self {
+        let mut pattern = self.pattern.clone();
+        for _ in 1..times {
+            pattern.push(Spring::Unknown);
+            pattern.extend(self.pattern.iter().cloned());
+        }
+
+        Self {
+            pattern,
+            expected_sizes: self.expected_sizes.repeat(times),
+        }
+    }
+
+    /// Number of arrangements that match this row.
+    pub fn valid_options(&self) -> u128 {
+        let mut cache = HashMap::new();
+        valid_options_for(self.pattern.clone(), self.expected_sizes.clone(), &mut cache)
+    }
+}
+
+/// Sum of the arrangements of every row as recorded.
+pub fn part1(document: &str) -> Result<u128, ParseError> {
+    Ok(sum_valid_options(&parse_document(document)?, 1))
+}
+
+/// Sum of the arrangements of every row once unfolded five times.
+pub fn part2(document: &str) -> Result<u128, ParseError> {
+    Ok(sum_valid_options(&parse_document(document)?, UNFOLD))
+}
+
+/// How many times the rows are unfolded in part 2.
+pub const UNFOLD: usize = 5;
+
+pub struct Day12 {
+    /// Times every row is unfolded in part 2, see [`UNFOLD`].
+    pub unfold: usize,
+}
+
+impl Default for Day12 {
+    fn default() -> Self {
+        Self { unfold: UNFOLD }
+    }
+}
+
+impl Solution for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn name(&self) -> &'static str {
+        "springs"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(sum_valid_options(&parse_document(input)?, self.unfold).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(
+            parse_document(input)?,
+            |rows| sum_valid_options(rows, 1),
+            |rows| sum_valid_options(rows, self.unfold),
+        )))
+    }
+}
+
+/// Parses one [`Row`] per line.
+pub fn parse_document(document: &str) -> Result<Vec<Row>, ParseError> {
+    aoc_core::parse_lines(document, Row::from_str)
+}
+
+/// Sum of the arrangements of every row, each unfolded `unfold` times.
+pub fn sum_valid_options(rows: &[Row], unfold: usize) -> u128 {
+    rows.par_iter()
+        .map(|row| row.unfold(unfold).valid_options())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spring_from_char() {
+        let springs: Vec<Spring> = "?.#".chars().map(|c| Spring::try_from(c).unwrap()).collect();
+        assert_eq!(
+            springs,
+            vec![Spring::Unknown, Spring::Operational, Spring::Damaged]
+        )
+    }
+
+    #[test]
+    fn test_row_from_str_errors() {
+        let error = Row::from_str("??.x# 1,1").unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidSpring, 4, "x"));
+
+        let error = Row::from_str("??..#").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingSizes);
+
+        let error = Row::from_str("??..# 1,,1").unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidSize, 9));
+    }
+
+    #[test]
+    fn test_row_unfold() {
+        let row = Row::from_str(".# 1").unwrap().unfold(3);
+        assert_eq!(row, Row::from_str(".#?.#?.# 1,1,1").unwrap());
+    }
+
+    #[test]
+    fn test_row_from_str() {
+        let row = Row::from_str("??..# 1,1").unwrap();
+        assert_eq!(
+            row,
+            Row {
+                pattern: vec![
+                    Spring::Unknown,
+                    Spring::Unknown,
+                    Spring::Operational,
+                    Spring::Operational,
+                    Spring::Damaged
+                ],
+                expected_sizes: vec![1, 1]
+            }
+        )
+    }
+
+    #[test]
+    fn test_spring_valid_options() {
+        assert_eq!(Row::from_str("??..# 1,1").unwrap().valid_options(), 2);
+        assert_eq!(Row::from_str("?###???????? 3,2,1").unwrap().valid_options(), 10);
+    }
+
+    #[test]
+    fn test_performance_of_big_expansion() {
+        let row = Row::from_str(".?.??????????#????#?.?.??????????#????#?.?.??????????#????#?.?.??????????#????#?.?.??????????#????#? 1,1,2,7,2,1,1,2,7,2,1,1,2,7,2,1,1,2,7,2,1,1,2,7,2").unwrap();
+        assert_eq!(row.valid_options(), 759375);
+    }
+}
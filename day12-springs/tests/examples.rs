@@ -0,0 +1,13 @@
+use aoc_core::{Answer, Solution};
+use day12_springs::Day12;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day12::default().part1(EXAMPLE).unwrap(), Answer::Number(21));
+    assert_eq!(
+        Day12::default().part2(EXAMPLE).unwrap(),
+        Answer::Number(525152)
+    );
+}
@@ -0,0 +1,188 @@
+//! Day 13: Point of Incidence
+//!
+//! Finds the lines of reflection in patterns of ash and rocks, with or
+//! without a smudge on the mirror.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+
+pub mod sequence;
+
+pub use sequence::Pattern;
+pub use sequence::Reflection;
+
+/// Parses the patterns, separated by blank lines.
+pub fn parse_document(document: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = Vec::new();
+    let mut line = 0;
+
+    for block in document.split("\n\n") {
+        patterns.push(Pattern::from_str(block).map_err(|e| e.below(line))?);
+        line += block.lines().count() + 1;
+    }
+
+    Ok(patterns)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    EmptyPattern,
+    RaggedLine,
+    TooWide,
+    InvalidCharacter,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::EmptyPattern => write!(f, "empty pattern"),
+            ParseErrorKind::RaggedLine => write!(f, "line length differs from the first line"),
+            ParseErrorKind::TooWide => write!(f, "patterns can be at most 64 wide"),
+            ParseErrorKind::InvalidCharacter => write!(f, "expected . or #"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// Summary of the reflections of all patterns.
+pub fn part1(patterns: &[Pattern]) -> Result<usize, NoReflection> {
+    summarize_smudged(patterns, 0)
+}
+
+/// Summary of the reflections of all patterns once their smudges are fixed.
+pub fn part2(patterns: &[Pattern]) -> Result<usize, NoReflection> {
+    summarize_smudged(patterns, SMUDGES)
+}
+
+/// How many smudges every mirror has in part 2.
+pub const SMUDGES: u32 = 1;
+
+pub struct Day13 {
+    /// Smudges fixed on every mirror in part 2, see [`SMUDGES`].
+    pub smudges: u32,
+}
+
+impl Default for Day13 {
+    fn default() -> Self {
+        Self { smudges: SMUDGES }
+    }
+}
+
+impl Solution for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn name(&self) -> &'static str {
+        "mirrors"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_document(input)?)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(summarize_smudged(&parse_document(input)?, self.smudges)?.into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(
+            parse_document(input)?,
+            |patterns| part1(patterns),
+            |patterns| summarize_smudged(patterns, self.smudges),
+        )))
+    }
+}
+
+/// Adds up the columns left of each vertical reflection and 100 times the
+/// rows above each horizontal one, once exactly `smudges` smudges are fixed
+/// on every mirror.
+pub fn summarize_smudged(patterns: &[Pattern], smudges: u32) -> Result<usize, NoReflection> {
+    patterns
+        .iter()
+        .enumerate()
+        .try_fold(0, |acc, (idx, pattern)| {
+            let reflection = pattern
+                .find_smudged_reflection(smudges)
+                .ok_or(NoReflection {
+                    pattern: idx + 1,
+                    smudges,
+                })?;
+
+            Ok(acc + score(reflection))
+        })
+}
+
+fn score((middle, reflection): (usize, Reflection)) -> usize {
+    if reflection == Reflection::Horizontal {
+        middle * 100
+    } else {
+        middle
+    }
+}
+
+/// A pattern without a line of reflection for the number of smudges asked
+/// for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoReflection {
+    /// Index of the pattern, starting at 1.
+    pub pattern: usize,
+    pub smudges: u32,
+}
+
+impl fmt::Display for NoReflection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pattern {} has no line of reflection with {} smudges",
+            self.pattern, self.smudges
+        )
+    }
+}
+
+impl Error for NoReflection {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_document_reports_line() {
+        let document = "#.#\n.#.\n\n##.\n#x.\n";
+        let error = parse_document(document).unwrap_err();
+
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidCharacter, 5, 2));
+    }
+
+    #[test]
+    fn test_summarize_smudged() {
+        let patterns = parse_document("#.\n#.\n\n##\n##").unwrap();
+
+        assert_eq!(summarize_smudged(&patterns, 0), Ok(200));
+        assert_eq!(part1(&patterns), Ok(200));
+        assert_eq!(
+            summarize_smudged(&patterns, 2),
+            Err(NoReflection {
+                pattern: 2,
+                smudges: 2
+            })
+        );
+
+        let patterns = parse_document("#.\n##").unwrap();
+        assert_eq!(
+            part1(&patterns),
+            Err(NoReflection {
+                pattern: 1,
+                smudges: 0
+            })
+        );
+    }
+}
@@ -1,32 +1,21 @@
-pub mod sequence;
+use clap::Parser;
+use day13_mirrors::{Day13, SMUDGES};
 
-use sequence::Pattern;
-use sequence::Reflection;
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    options: aoc_core::cli::Options,
 
-fn main() {
-    let now = std::time::Instant::now();
-    // Open file passed in ARGV
-    let args: Vec<String> = std::env::args().collect();
-    // Print usage if no file is passed
-    if args.len() < 2 {
-        println!("Usage: {} <filename>", args[0]);
-        return;
-    }
-
-    let filename = &args[1];
-    let document =
-        std::fs::read_to_string(filename).expect("Something went wrong reading the file");
-    
-    let patterns: Vec<Pattern> = document.split("\n\n").map(Pattern::from).collect();
-    let sum = patterns.iter().map(|pattern| pattern.find_smudge_reflection()).fold(0, |acc, (middle, reflection)| {
-        if reflection == Reflection::Horizontal {
-            acc + middle * 100
-        } else {
-            acc + middle
-        }
-    });
+    /// How many smudges every mirror has in part 2
+    #[arg(long, default_value_t = SMUDGES, conflicts_with = "check")]
+    smudges: u32,
+}
 
-    println!("Sum: {}", sum);
+fn main() {
+    let args: Args = aoc_core::cli::parse(&Day13::default());
+    let day = Day13 {
+        smudges: args.smudges,
+    };
 
-    println!("Runtime: {:?}", now.elapsed());
-}
\ No newline at end of file
+    aoc_core::cli::run(&day, &args.options);
+}
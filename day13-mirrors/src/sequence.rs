@@ -1,5 +1,10 @@
-use core::panic;
+use std::str::FromStr;
 
+use aoc_grid::Grid;
+
+use crate::{ParseError, ParseErrorKind};
+
+/// A row or column of a pattern, with rocks as set bits.
 pub type Sequence = u64;
 
 #[derive(Debug, PartialEq)]
@@ -8,80 +13,54 @@ pub enum Reflection {
     Vertical,
 }
 
+/// A pattern of ash and rocks, stored both row- and column-wise.
 #[derive(Debug)]
 pub struct Pattern {
     rows: Vec<Sequence>,
     columns: Vec<Sequence>,
 }
 
-impl From<&str> for Pattern {
-    fn from(document: &str) -> Self {
-        let column_count = document.lines().next().unwrap().len();
-        let mut column_chars: Vec<Vec<char>> = vec![vec![]; column_count];
-
-        let rows = Box::new(document.lines().map(str::chars))
-            .inspect(|chars| {
-                chars
-                    .clone()
-                    .enumerate()
-                    .for_each(|(idx, c)| column_chars[idx].push(c))
-            })
-            .map(|chars| parse_sequence(chars))
-            .collect();
-
-        let columns = column_chars
-            .into_iter()
-            .map(|chars| parse_sequence(chars.into_iter()))
-            .collect();
+impl FromStr for Pattern {
+    type Err = ParseError;
 
-        Self { rows, columns }
-    }
-}
+    fn from_str(document: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(document, ParseErrorKind::RaggedLine, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseErrorKind::InvalidCharacter),
+        })?;
 
-fn parse_sequence<I>(iter: I) -> Sequence
-where
-    I: IntoIterator<Item = char>,
-{
-    let mut sequence = 0;
+        if grid.width() == 0 {
+            return Err(ParseError::new(ParseErrorKind::EmptyPattern, document, document));
+        }
+        if grid.width() > Sequence::BITS as usize {
+            let line = document.lines().next().unwrap_or_default();
+            return Err(ParseError::new(ParseErrorKind::TooWide, line, line));
+        }
 
-    for c in iter {
-        let bit = match c {
-            '#' => 1,
-            '.' => 0,
-            _ => panic!("Not a known character"),
-        };
+        let rows = grid.rows().map(to_sequence).collect();
+        let columns = grid.columns().map(to_sequence).collect();
 
-        sequence = (sequence << 1) | bit;
+        Ok(Self { rows, columns })
     }
-
-    sequence
 }
 
-// This logic needs some love, but it works for now
-fn find_reflection(sequences: &Vec<Sequence>) -> Option<usize> {
-    let mut sequences = sequences.clone();
-
-    while sequences.len() > 1 {
-        if sequences.iter().rev().copied().collect::<Vec<u64>>() == sequences {
-            return Some(sequences.len() / 2);
-        }
-
-        sequences.pop();
-    }
-
-    None
+/// Packs rocks into bits, the first one ending up as the most significant.
+fn to_sequence<'a>(rocks: impl IntoIterator<Item = &'a bool>) -> Sequence {
+    rocks
+        .into_iter()
+        .fold(0, |sequence, &rock| (sequence << 1) | rock as Sequence)
 }
 
-fn smudge_reflection_idx(sequences: &Vec<Sequence>) -> Option<usize> {
+fn smudge_reflection_idx(sequences: &[Sequence], smudges: u32) -> Option<usize> {
     (0..sequences.len() - 1).find_map(|idx| {
         if (0..idx + 1)
-            .into_iter()
             .rev()
             .zip(idx + 1..sequences.len())
             .fold(0, |acc, (i, j)| {
                 acc + (sequences[i] ^ sequences[j]).count_ones()
             })
-            == 1
+            == smudges
         {
             Some(idx + 1)
         } else {
@@ -91,30 +70,26 @@ fn smudge_reflection_idx(sequences: &Vec<Sequence>) -> Option<usize> {
 }
 
 impl Pattern {
-    pub fn find_smudge_reflection(&self) -> (usize, Reflection) {
-        if let Some(reflection) = smudge_reflection_idx(&self.rows) {
-            (reflection, Reflection::Horizontal)
-        } else if let Some(reflection) = smudge_reflection_idx(&self.columns) {
-            (reflection, Reflection::Vertical)
-        } else {
-            unreachable!()
-        }
+    /// The line of reflection, as the number of rows above or columns left
+    /// of it, if there is one.
+    pub fn find_reflection(&self) -> Option<(usize, Reflection)> {
+        self.find_smudged_reflection(0)
     }
 
-    pub fn find_reflection(&self) -> (usize, Reflection) {
-        if let Some(reflection) = find_reflection(&self.rows) {
-            (reflection, Reflection::Horizontal)
-        } else if let Some(reflection) = find_reflection(&self.columns) {
-            (reflection, Reflection::Vertical)
-        } else if let Some(reflection) = find_reflection(&self.rows.iter().rev().copied().collect())
-        {
-            (self.rows.len() - reflection, Reflection::Horizontal)
-        } else if let Some(reflection) =
-            find_reflection(&self.columns.iter().rev().copied().collect())
-        {
-            (self.columns.len() - reflection, Reflection::Vertical)
+    /// The line of reflection once exactly one smudge is fixed, if there is
+    /// one.
+    pub fn find_smudge_reflection(&self) -> Option<(usize, Reflection)> {
+        self.find_smudged_reflection(1)
+    }
+
+    /// The line of reflection once exactly `smudges` smudges are fixed, if
+    /// there is one.
+    pub fn find_smudged_reflection(&self, smudges: u32) -> Option<(usize, Reflection)> {
+        if let Some(reflection) = smudge_reflection_idx(&self.rows, smudges) {
+            Some((reflection, Reflection::Horizontal))
         } else {
-            unreachable!()
+            smudge_reflection_idx(&self.columns, smudges)
+                .map(|reflection| (reflection, Reflection::Vertical))
         }
     }
 }
@@ -140,39 +115,68 @@ mod tests {
 #....#..#";
 
     #[test]
-    fn test_parse_sequence() {
-        let sequence = parse_sequence(vec!['.', '#', '.', '#'].into_iter());
-        assert_eq!(sequence, 0b0101);
+    fn test_to_sequence() {
+        assert_eq!(to_sequence(&[false, true, false, true]), 0b0101);
     }
 
     #[test]
     fn test_pattern_from_str() {
-        let pattern = Pattern::from(FIRST_PATTERN);
+        let pattern = Pattern::from_str(FIRST_PATTERN).unwrap();
         assert_eq!(pattern.rows.len(), 7);
         assert_eq!(pattern.columns.len(), 9);
 
         assert_eq!(pattern.rows[0], 0b101100110);
         assert_eq!(pattern.columns[0], 0b1011001);
+        assert_eq!(pattern.columns[8], 0b0011000);
+    }
+
+    #[test]
+    fn test_pattern_from_str_errors() {
+        let error = Pattern::from_str("#.#\n#.").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::RaggedLine, 2));
+
+        let error = Pattern::from_str("#.#\n.#x").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidCharacter, 2, 3));
+
+        let error = Pattern::from_str(&"#".repeat(65)).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::TooWide);
+
+        let error = Pattern::from_str("").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::EmptyPattern);
     }
 
     #[test]
     fn test_find_middle() {
-        let pattern = Pattern::from(FIRST_PATTERN);
-        assert_eq!(pattern.find_reflection(), (5, Reflection::Vertical));
+        let pattern = Pattern::from_str(FIRST_PATTERN).unwrap();
+        assert_eq!(pattern.find_reflection(), Some((5, Reflection::Vertical)));
 
-        let pattern = Pattern::from(SECOND_PATTERN);
-        assert_eq!(pattern.find_reflection(), (4, Reflection::Horizontal));
+        let pattern = Pattern::from_str(SECOND_PATTERN).unwrap();
+        assert_eq!(pattern.find_reflection(), Some((4, Reflection::Horizontal)));
+
+        // A row repeating around the middle one isn't a line of reflection
+        let pattern = Pattern::from_str("#.\n##\n#.").unwrap();
+        assert_eq!(pattern.find_reflection(), None);
     }
 
     #[test]
     fn test_find_smudge_middle() {
-        let pattern = Pattern::from(FIRST_PATTERN);
+        let pattern = Pattern::from_str(FIRST_PATTERN).unwrap();
         assert_eq!(
             pattern.find_smudge_reflection(),
-            (3, Reflection::Horizontal)
+            Some((3, Reflection::Horizontal))
         );
 
-        let pattern = Pattern::from(SECOND_PATTERN);
-        assert_eq!(pattern.find_smudge_reflection(), (1, Reflection::Horizontal));
+        let pattern = Pattern::from_str(SECOND_PATTERN).unwrap();
+        assert_eq!(pattern.find_smudge_reflection(), Some((1, Reflection::Horizontal)));
+    }
+
+    #[test]
+    fn test_find_smudged_reflection() {
+        let pattern = Pattern::from_str(FIRST_PATTERN).unwrap();
+        assert_eq!(
+            pattern.find_smudged_reflection(0),
+            Some((5, Reflection::Vertical))
+        );
+        assert_eq!(pattern.find_smudged_reflection(40), None);
     }
 }
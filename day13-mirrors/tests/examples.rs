@@ -0,0 +1,16 @@
+use aoc_core::{Answer, Solution};
+use day13_mirrors::Day13;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(
+        Day13::default().part1(EXAMPLE).unwrap(),
+        Answer::Number(405)
+    );
+    assert_eq!(
+        Day13::default().part2(EXAMPLE).unwrap(),
+        Answer::Number(400)
+    );
+}
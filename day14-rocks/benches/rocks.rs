@@ -0,0 +1,19 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use day14_rocks::{parse_platform, shift_and_rotate};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+fn bench_shift_and_rotate(c: &mut Criterion) {
+    let platform = parse_platform(EXAMPLE).unwrap();
+
+    c.bench_function("shift_and_rotate", |b| {
+        b.iter_batched_ref(
+            || platform.clone(),
+            |platform| shift_and_rotate(black_box(platform)),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_shift_and_rotate);
+criterion_main!(benches);
@@ -0,0 +1,214 @@
+//! Day 14: Parabolic Reflector Dish
+//!
+//! Tilts a platform of round and cube-shaped rocks and measures the load on
+//! its north support beams.
+
+use std::collections::{hash_map::DefaultHasher, HashMap};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+use aoc_grid::Grid;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Rock {
+    Round,
+    Cube,
+    None,
+}
+
+impl fmt::Display for Rock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rock::Round => write!(f, "O"),
+            Rock::Cube => write!(f, "#"),
+            Rock::None => write!(f, "."),
+        }
+    }
+}
+
+pub type Platform = Grid<Rock>;
+
+/// Load on the north beams after tilting the platform north once.
+pub fn part1(platform: &Platform) -> usize {
+    let mut platform = platform.clone();
+    shift_and_rotate(&mut platform);
+
+    count_east_weight(&platform)
+}
+
+/// Load on the north beams after a billion spin cycles.
+pub fn part2(platform: &Platform) -> usize {
+    let mut loop_detector = HashMap::new();
+    let mut platform = platform.clone();
+    let mut rotations_left = 1_000_000_000_u32;
+    let mut i = 0;
+
+    while rotations_left > 0 {
+        for _ in 0..4 {
+            shift_and_rotate(&mut platform)
+        }
+        rotations_left -= 1;
+        i += 1;
+
+        if let Some(original_idx) = loop_detector.insert(hash_platform(&platform), i) {
+            rotations_left %= i - original_idx;
+        }
+    }
+
+    count_north_weight(&platform)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn name(&self) -> &'static str {
+        "rocks"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_platform(input)?).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&parse_platform(input)?).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(parse_platform(input)?, part1, part2)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidRock,
+    NotSquare,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidRock => write!(f, "expected O, # or ."),
+            ParseErrorKind::NotSquare => write!(f, "the platform should be square"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// Parses the platform, which has to be square for [`shift_and_rotate`] to
+/// work.
+pub fn parse_platform(platform: &str) -> Result<Platform, ParseError> {
+    let parsed = Grid::parse(platform, ParseErrorKind::NotSquare, |c| match c {
+        'o' | 'O' => Ok(Rock::Round),
+        '#' => Ok(Rock::Cube),
+        '.' => Ok(Rock::None),
+        _ => Err(ParseErrorKind::InvalidRock),
+    })?;
+
+    if parsed.width() != parsed.height() {
+        let line = platform.lines().next().unwrap_or_default();
+        return Err(ParseError::new(ParseErrorKind::NotSquare, line, line));
+    }
+
+    Ok(parsed)
+}
+
+/// Tilts the platform north, then rotates it clockwise so the next call
+/// tilts what used to be west.
+pub fn shift_and_rotate(platform: &mut Platform) {
+    let len = platform.width();
+    let mut shifted = Vec::with_capacity(len * len);
+
+    for x in 0..len {
+        let mut empties = 0;
+        let mut rounds = 0;
+
+        for &rock in platform.column(x).rev() {
+            match rock {
+                Rock::Round => rounds += 1,
+                Rock::None => empties += 1,
+                Rock::Cube => {
+                    shifted.extend(vec![Rock::None; empties]);
+                    shifted.extend(vec![Rock::Round; rounds]);
+                    shifted.push(Rock::Cube);
+                    empties = 0;
+                    rounds = 0;
+                }
+            }
+        }
+        shifted.extend(vec![Rock::None; empties]);
+        shifted.extend(vec![Rock::Round; rounds]);
+    }
+
+    *platform = Grid::from_cells(len, shifted)
+}
+
+pub fn count_north_weight(platform: &Platform) -> usize {
+    platform
+        .rows()
+        .rev()
+        .enumerate()
+        .map(|(idx, column)| (idx + 1) * column.iter().filter(|&rock| *rock == Rock::Round).count())
+        .sum()
+}
+
+// After a single shift_and_rotate, north is on the east side
+pub fn count_east_weight(platform: &Platform) -> usize {
+    platform
+        .rows()
+        .flat_map(|row| row.iter().enumerate())
+        .filter(|(_, &rock)| rock == Rock::Round)
+        .map(|(idx, _)| idx + 1)
+        .sum()
+}
+
+fn hash_platform(platform: &Platform) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    platform.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static PLATFORM: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test_count_east_weight() {
+        let mut platform = parse_platform(PLATFORM).unwrap();
+        shift_and_rotate(&mut platform);
+
+        assert_eq!(count_east_weight(&platform), 136);
+    }
+
+    #[test]
+    fn test_parse_platform_errors() {
+        let error = parse_platform("O.\n#x").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidRock, 2, 2));
+
+        let error = parse_platform("O.\n#.O").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::NotSquare, 2));
+
+        let error = parse_platform("O.O\n#.O").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::NotSquare, 1));
+    }
+}
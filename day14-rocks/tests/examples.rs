@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use day14_rocks::Day14;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day14.part1(EXAMPLE).unwrap(), Answer::Number(136));
+    assert_eq!(Day14.part2(EXAMPLE).unwrap(), Answer::Number(64));
+}
@@ -1,34 +1,49 @@
-#[derive(Debug)]
-enum Modifier {
+use crate::{ParseError, ParseErrorKind};
+
+#[derive(Debug, PartialEq)]
+pub enum Modifier {
   Add,
   Remove,
 }
 
-#[derive(Debug)]
-struct Operation {
-  key: String,
-  modifier: Modifier,
-  power: u16
+/// A parsed step: put a lens with `power` in the box for `key`, or take it
+/// out.
+#[derive(Debug, PartialEq)]
+pub struct Operation {
+  pub key: String,
+  pub modifier: Modifier,
+  pub power: u16
 }
 
-impl From<&str> for Operation {
-  fn from(command: &str) -> Self {
-    let modifier_idx = command.find(|c| c == '=' || c == '-').unwrap();
+impl TryFrom<&str> for Operation {
+  type Error = ParseError;
+
+  fn try_from(command: &str) -> Result<Self, Self::Error> {
+    let modifier_idx = command
+      .find(['=', '-'])
+      .ok_or_else(|| ParseError::new(ParseErrorKind::MissingOperation, command, command))?;
     let key = command[..modifier_idx].to_string();
     let modifier = &command[modifier_idx..=modifier_idx];
     let power = &command[modifier_idx + 1..];
 
-    let modifier = match modifier {
-      "=" => Modifier::Add,
-      "-" => Modifier::Remove,
-      _ => unreachable!()
+    let (modifier, power) = match modifier {
+      "=" => {
+        let power = power
+          .parse()
+          .map_err(|_| ParseError::new(ParseErrorKind::InvalidFocalLength, command, power))?;
+        (Modifier::Add, power)
+      },
+      _ if !power.is_empty() => {
+        return Err(ParseError::new(ParseErrorKind::TrailingText, command, power));
+      },
+      _ => (Modifier::Remove, 0),
     };
 
-    Self {
+    Ok(Self {
       key,
       modifier,
-      power: power.parse().unwrap_or(0)
-    }
+      power
+    })
   }
 }
 
@@ -44,6 +59,7 @@ struct Box {
   power: u16
 }
 
+/// The 256 boxes of the HASHMAP, each holding an ordered list of lenses.
 #[derive(Debug)]
 pub struct Boxes {
   boxes: [Vec<Box>; 256]
@@ -58,9 +74,8 @@ impl Boxes {
     }
   }
 
-  pub fn process_command(&mut self, command: &str) {
-    let op: Operation = command.into();
-    
+  /// Applies a single `label=focal` or `label-` step.
+  pub fn process_command(&mut self, op: &Operation) {
     let bx = &mut self.boxes[op.idx()];
 
     match op.modifier {
@@ -69,7 +84,7 @@ impl Boxes {
           bx[box_idx].power = op.power;
         } else {
           bx.push(Box {
-            key: op.key,
+            key: op.key.clone(),
             power: op.power
           });
         }
@@ -82,6 +97,7 @@ impl Boxes {
     }
   }
 
+  /// Focusing power of all lenses in all boxes.
   pub fn total_power(&self) -> u64 {
     self.boxes.iter().enumerate().flat_map(|(idx, slots)| {
       slots.iter().enumerate().map(move |(slot_idx, slot)| {
@@ -91,6 +107,13 @@ impl Boxes {
   }
 }
 
+impl Default for Boxes {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// The HASH algorithm: a number between 0 and 255 for any string.
 pub fn hash(string: &str) -> u8 {
   string.chars().fold(0, |hash, c| 
     hash.overflowing_add(c as u8).0.overflowing_mul(17).0
@@ -108,4 +131,25 @@ mod tests {
     assert_eq!(hash("rn"), 0);
     assert_eq!(hash("cm"), 0);
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn test_operation_try_from() {
+    assert_eq!(
+      Operation::try_from("rn=1"),
+      Ok(Operation { key: "rn".to_string(), modifier: Modifier::Add, power: 1 })
+    );
+    assert_eq!(
+      Operation::try_from("cm-"),
+      Ok(Operation { key: "cm".to_string(), modifier: Modifier::Remove, power: 0 })
+    );
+
+    let error = Operation::try_from("rn").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::MissingOperation);
+
+    let error = Operation::try_from("rn=x").unwrap_err();
+    assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidFocalLength, 4));
+
+    let error = Operation::try_from("cm-2").unwrap_err();
+    assert_eq!((error.kind, error.column), (ParseErrorKind::TrailingText, 4));
+  }
+}
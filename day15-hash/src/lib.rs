@@ -0,0 +1,108 @@
+//! Day 15: Lens Library
+//!
+//! Runs the HASH algorithm over the initialization sequence and arranges
+//! lenses into boxes by it.
+
+use std::fmt;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+
+pub mod boxes;
+
+pub use boxes::hash;
+pub use boxes::{Boxes, Operation};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingOperation,
+    InvalidFocalLength,
+    TrailingText,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingOperation => write!(f, "expected \"<label>=<focal length>\" or \"<label>-\""),
+            ParseErrorKind::InvalidFocalLength => write!(f, "invalid focal length"),
+            ParseErrorKind::TrailingText => write!(f, "unexpected text after \"-\""),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// A step of the initialization sequence, as written and as parsed.
+#[derive(Debug)]
+pub struct Step<'a> {
+    pub text: &'a str,
+    pub operation: Operation,
+}
+
+/// Parses the comma separated steps of the initialization sequence.
+pub fn parse_document(document: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    document
+        .trim()
+        .split(',')
+        .map(|text| {
+            let operation = Operation::try_from(text).map_err(|e| e.within(document, text))?;
+            Ok(Step { text, operation })
+        })
+        .collect()
+}
+
+/// Sum of the HASH of every step.
+pub fn part1(steps: &[Step]) -> u64 {
+    steps.iter().map(|step| hash(step.text) as u64).sum()
+}
+
+/// Focusing power of the lenses once every step has been processed.
+pub fn part2(steps: &[Step]) -> u64 {
+    let mut boxes = Boxes::new();
+    for step in steps {
+        boxes.process_command(&step.operation)
+    }
+    boxes.total_power()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn name(&self) -> &'static str {
+        "hash"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_document(input)?).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&parse_document(input)?).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(
+            parse_document(input)?,
+            |steps| part1(steps),
+            |steps| part2(steps),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_document_reports_column() {
+        let error = parse_document("rn=1,cm-,qp=x\n").unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidFocalLength, 13));
+    }
+}
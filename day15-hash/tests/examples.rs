@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use day15_hash::Day15;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day15.part1(EXAMPLE).unwrap(), Answer::Number(1320));
+    assert_eq!(Day15.part2(EXAMPLE).unwrap(), Answer::Number(145));
+}
@@ -0,0 +1,15 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day16_beams::Map;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+fn bench_traverse(c: &mut Criterion) {
+    let map = Map::try_from(EXAMPLE).unwrap();
+
+    c.bench_function("Map::traverse", |b| {
+        b.iter(|| map.traverse(black_box(((0, 0), (0, 1)))))
+    });
+}
+
+criterion_group!(benches, bench_traverse);
+criterion_main!(benches);
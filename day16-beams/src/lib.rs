@@ -0,0 +1,187 @@
+//! Day 16: The Floor Will Be Lava
+//!
+//! Traces a beam of light bouncing off mirrors and splitters and counts the
+//! tiles it energizes.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+use aoc_grid::Grid;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn name(&self) -> &'static str {
+        "beams"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&Map::try_from(input)?).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&Map::try_from(input)?).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(Map::try_from(input)?, part1, part2)))
+    }
+}
+
+pub use aoc_grid::Point;
+pub type Vector = (isize, isize);
+
+/// The contraption of mirrors and splitters.
+pub struct Map {
+    pub tiles: Grid<char>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    EmptyMap,
+    InvalidTile,
+    RaggedLine,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::EmptyMap => write!(f, "empty map"),
+            ParseErrorKind::InvalidTile => write!(f, "expected one of . / \\ - |"),
+            ParseErrorKind::RaggedLine => write!(f, "line length differs from the first line"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::parse(string, ParseErrorKind::RaggedLine, |c| match c {
+            '.' | '/' | '\\' | '-' | '|' => Ok(c),
+            _ => Err(ParseErrorKind::InvalidTile),
+        })?;
+
+        // Beams enter at the edges, so there has to be at least one tile
+        if tiles.width() == 0 {
+            return Err(ParseError::new(ParseErrorKind::EmptyMap, string, &string[..0]));
+        }
+
+        Ok(Map { tiles })
+    }
+}
+
+impl Map {
+    /// Steps from `location` along `vector`, or `None` when that leaves the map.
+    pub fn resolve_location(&self, location: &Point, vector: &Vector) -> Option<Point> {
+        self.tiles.offset(*location, *vector)
+    }
+
+    /// Number of tiles energized by a beam entering at the given point and
+    /// heading.
+    pub fn traverse(&self, entry: (Point, Vector)) -> u64 {
+        let mut visited_locations: HashSet<Point> = HashSet::new();
+        let mut visit_queue: Vec<(Point, Vector)> = vec![];
+        let mut loop_detector: HashSet<(Point, Vector)> = HashSet::new();
+        visit_queue.push(entry);
+
+        while let Some((location, vector)) = visit_queue.pop() {
+            if !loop_detector.insert((location, vector)) {
+                continue;
+            }
+
+            visited_locations.insert(location);
+
+            let next_vectors = match self.tiles.get(location) {
+                Some('.') => vec![vector],
+                Some('\\') => match vector {
+                    (0, 1) => vec![(1, 0)],
+                    (0, -1) => vec![(-1, 0)],
+                    (1, 0) => vec![(0, 1)],
+                    (-1, 0) => vec![(0, -1)],
+                    _ => unreachable!(),
+                },
+                Some('/') => match vector {
+                    (0, 1) => vec![(-1, 0)],
+                    (0, -1) => vec![(1, 0)],
+                    (1, 0) => vec![(0, -1)],
+                    (-1, 0) => vec![(0, 1)],
+                    _ => unreachable!(),
+                },
+                Some('-') => match vector {
+                    (0, 1) | (0, -1) => vec![vector],
+                    (1, 0) | (-1, 0) => vec![(0, 1), (0, -1)],
+                    _ => unreachable!(),
+                },
+                Some('|') => match vector {
+                    (1, 0) | (-1, 0) => vec![vector],
+                    (0, 1) | (0, -1) => vec![(1, 0), (-1, 0)],
+                    _ => unreachable!(),
+                }
+
+                _ => unreachable!(),
+            };
+
+            for next_vector in next_vectors {
+                if let Some(next_location) = self.resolve_location(&location, &next_vector) {
+                    visit_queue.push((next_location, next_vector));
+                }
+            }
+
+        }
+
+        visited_locations.len() as u64
+    }
+}
+
+/// Tiles energized by a beam entering the top left corner heading right.
+pub fn part1(map: &Map) -> u64 {
+    map.traverse(((0, 0), (0, 1)))
+}
+
+/// Most tiles energized by a beam entering from any edge tile.
+pub fn part2(map: &Map) -> u64 {
+    let mut max_visited = 0;
+
+    let (bottom, right) = (map.tiles.height() - 1, map.tiles.width() - 1);
+
+    for y in 0..=right {
+        max_visited = max_visited.max(map.traverse(((0, y), (1, 0))));
+        max_visited = max_visited.max(map.traverse(((bottom, y), (-1, 0))));
+    }
+    for x in 0..=bottom {
+        max_visited = max_visited.max(map.traverse(((x, 0), (0, 1))));
+        max_visited = max_visited.max(map.traverse(((x, right), (0, -1))));
+    }
+
+    max_visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_try_from_errors() {
+        let error = Map::try_from(".|.\n.x.").err().unwrap();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidTile, 2, 2));
+
+        let error = Map::try_from(".|.\n..").err().unwrap();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::RaggedLine, 2));
+
+        let error = Map::try_from("").err().unwrap();
+        assert_eq!(error.kind, ParseErrorKind::EmptyMap);
+        assert!(Map::try_from("\n\n").is_err());
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use day16_beams::Day16;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day16.part1(EXAMPLE).unwrap(), Answer::Number(46));
+    assert_eq!(Day16.part2(EXAMPLE).unwrap(), Answer::Number(51));
+}
@@ -0,0 +1,278 @@
+//! Day 18: Lavaduct Lagoon
+//!
+//! Follows the dig plan around the edge of the lagoon and computes how much
+//! lava it can hold.
+
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::{Solution, SolveResult};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn name(&self) -> &'static str {
+        "lagoon"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(input)?.into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A single step of the dig plan.
+#[derive(Debug, PartialEq)]
+pub struct Move {
+    pub direction: Direction,
+    pub distance: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidDirection,
+    InvalidDistance,
+    MissingColor,
+    InvalidColor,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidDirection => write!(f, "invalid direction"),
+            ParseErrorKind::InvalidDistance => write!(f, "invalid distance"),
+            ParseErrorKind::MissingColor => write!(f, "expected \"<direction> <distance> (#<colour>)\""),
+            ParseErrorKind::InvalidColor => write!(f, "expected a colour like #70c710"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+impl TryFrom<Hex<'_>> for Move {
+    type Error = ParseError;
+
+    fn try_from(hex: Hex) -> Result<Self, Self::Error> {
+        let hex = hex.0;
+        let invalid = || ParseError::new(ParseErrorKind::InvalidColor, hex, hex);
+
+        if hex.len() != 7 || !hex.starts_with('#') {
+            return Err(invalid());
+        }
+        let distance = i64::from_str_radix(&hex[1..=5], 16).map_err(|_| invalid())?;
+
+        let direction = match &hex[6..] {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            text => return Err(ParseError::new(ParseErrorKind::InvalidDirection, hex, text)),
+        };
+
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, distance) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidDirection, s, s))?;
+        let distance = distance
+            .parse::<i64>()
+            .map_err(|_| ParseError::new(ParseErrorKind::InvalidDistance, s, distance))?;
+
+        let direction = match direction {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(ParseError::new(ParseErrorKind::InvalidDirection, s, direction)),
+        };
+
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+/// The colour code of a step, which actually encodes the real step.
+pub struct Hex<'a>(pub &'a str);
+
+pub type Point = (i64, i64);
+
+/// The corners of the dug out trench, and its length.
+pub struct Lagoon {
+    pub circumference: i64,
+    pub points: Vec<Point>,
+}
+
+impl From<Vec<Move>> for Lagoon {
+    fn from(steps: Vec<Move>) -> Self {
+        let mut points = Vec::new();
+        let mut circumference = 0;
+
+        points.push((0, 0));
+
+        for mv in steps {
+            let last_point = points.last().unwrap();
+            let distance = mv.distance;
+
+            circumference += distance;
+
+            match mv.direction {
+                Direction::Up => points.push((last_point.0 - distance, last_point.1)),
+                Direction::Down => points.push((last_point.0 + distance, last_point.1)),
+                Direction::Left => points.push((last_point.0, last_point.1 - distance)),
+                Direction::Right => points.push((last_point.0, last_point.1 + distance)),
+            }
+        }
+
+        assert_eq!(points.first(), points.last(), "Point area should be closed");
+
+        Self {
+            circumference,
+            points,
+        }
+    }
+}
+
+impl Lagoon {
+    /// Area enclosed by the corner points, using the shoelace formula.
+    pub fn area(&self) -> i64 {
+        self.points
+            .windows(2)
+            .map(|pair| {
+                (pair[0].0 * pair[1].1) - (pair[0].1 * pair[1].0)
+            })
+            .sum::<i64>()
+            .abs()
+            / 2
+    }
+
+    /// Cubic meters of lava held: the interior plus the trench itself.
+    pub fn volume(&self) -> i64 {
+        self.area() + self.circumference / 2 + 1
+    }
+}
+
+// Splits a `R 6 (#70c710)` line into the plan and the colour code
+fn split_line(line: &str) -> Result<(&str, &str), ParseError> {
+    line.rsplit_once(' ')
+        .and_then(|(plan, color)| Some((plan, color.strip_prefix('(')?.strip_suffix(')')?)))
+        .ok_or_else(|| ParseError::new(ParseErrorKind::MissingColor, line, line))
+}
+
+/// Parses the dig plan as written, ignoring the colour codes.
+pub fn parse_moves(document: &str) -> Result<Vec<Move>, ParseError> {
+    aoc_core::parse_lines(document, |line| {
+        let (plan, _) = split_line(line)?;
+        Move::from_str(plan).map_err(|e| e.within(line, plan))
+    })
+}
+
+/// Parses the dig plan from the colour codes.
+pub fn parse_hex_moves(document: &str) -> Result<Vec<Move>, ParseError> {
+    aoc_core::parse_lines(document, |line| {
+        let (_, hex) = split_line(line)?;
+        Move::try_from(Hex(hex)).map_err(|e| e.within(line, hex))
+    })
+}
+
+/// Lava held by the lagoon dug following the plan as written.
+pub fn part1(document: &str) -> Result<i64, ParseError> {
+    Ok(Lagoon::from(parse_moves(document)?).volume())
+}
+
+/// Lava held by the lagoon dug following the colour codes.
+pub fn part2(document: &str) -> Result<i64, ParseError> {
+    Ok(Lagoon::from(parse_hex_moves(document)?).volume())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_from() {
+        assert_eq!(
+            Move::from_str("U 1").unwrap(),
+            Move {
+                direction: Direction::Up,
+                distance: 1
+            }
+        );
+        assert_eq!(
+            Move::from_str("D 1").unwrap(),
+            Move {
+                direction: Direction::Down,
+                distance: 1
+            }
+        );
+        assert_eq!(
+            Move::from_str("L 1").unwrap(),
+            Move {
+                direction: Direction::Left,
+                distance: 1
+            }
+        );
+        assert_eq!(
+            Move::from_str("R 1").unwrap(),
+            Move {
+                direction: Direction::Right,
+                distance: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_move_from_hex() {
+        assert_eq!(
+            Move::try_from(Hex("#70c710")).unwrap(),
+            Move {
+                direction: Direction::Right,
+                distance: 461937
+            }
+        )
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_moves("R 6 (#70c710)\nX 5 (#0dc571)").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidDirection, 2, 1));
+
+        let error = parse_moves("R six (#70c710)").unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidDistance, 3));
+
+        let error = parse_moves("R 6").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingColor);
+
+        let error = parse_hex_moves("R 6 (#70c714)").unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidDirection, 12));
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use day18_lagoon::Day18;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day18.part1(EXAMPLE).unwrap(), Answer::Number(62));
+    assert_eq!(Day18.part2(EXAMPLE).unwrap(), Answer::Number(952408144115));
+}
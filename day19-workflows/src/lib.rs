@@ -0,0 +1,450 @@
+//! Day 19: Aplenty
+//!
+//! Runs machine parts through a system of workflows and counts which
+//! ratings would be accepted.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn name(&self) -> &'static str {
+        "workflows"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_document(input)?).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&parse_document(input)?).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(parse_document(input)?, part1, part2)))
+    }
+}
+
+/// A single rule of a workflow, sending a part to its destination.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Rule {
+    Lt(char, u32, String),
+    Gt(char, u32, String),
+    Default(String),
+}
+
+const ACCEPTED: &str = "A";
+const REJECTED: &str = "R";
+const ENTRANCE: &str = "in";
+
+const RATINGS: [char; 4] = ['x', 'm', 'a', 's'];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidWorkflow,
+    InvalidRule,
+    InvalidRating,
+    InvalidValue,
+    UnknownWorkflow,
+    MissingEntrance,
+    MissingParts,
+    InvalidPart,
+    MissingRating(char),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidWorkflow => write!(f, "expected \"<name>{{<rules>}}\""),
+            ParseErrorKind::InvalidRule => write!(f, "expected \"<rating><<|><value>:<destination>\""),
+            ParseErrorKind::InvalidRating => write!(f, "ratings are x, m, a or s"),
+            ParseErrorKind::InvalidValue => write!(f, "invalid rating value"),
+            ParseErrorKind::UnknownWorkflow => write!(f, "no workflow with this name"),
+            ParseErrorKind::MissingEntrance => write!(f, "no \"{}\" workflow to start at", ENTRANCE),
+            ParseErrorKind::MissingParts => write!(f, "expected a blank line followed by the parts"),
+            ParseErrorKind::InvalidPart => write!(f, "expected \"{{x=<value>,m=<value>,a=<value>,s=<value>}}\""),
+            ParseErrorKind::MissingRating(rating) => write!(f, "part has no {} rating", rating),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+fn parse_rating(line: &str, text: &str) -> Result<char, ParseError> {
+    match text.chars().next() {
+        Some(c) if text.len() == 1 && RATINGS.contains(&c) => Ok(c),
+        _ => Err(ParseError::new(ParseErrorKind::InvalidRating, line, text)),
+    }
+}
+
+fn parse_value(line: &str, text: &str) -> Result<u32, ParseError> {
+    text.parse::<u32>()
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidValue, line, text))
+}
+
+impl TryFrom<&str> for Rule {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        if let Some((condition, destination)) = str.split_once(':') {
+            let Some(idx) = condition.find(['<', '>']) else {
+                return Err(ParseError::new(ParseErrorKind::InvalidRule, str, str));
+            };
+            let key = parse_rating(str, &condition[..idx])?;
+            let value = parse_value(str, &condition[idx + 1..])?;
+
+            match &condition[idx..=idx] {
+                "<" => Ok(Rule::Lt(key, value, destination.to_string())),
+                _ => Ok(Rule::Gt(key, value, destination.to_string())),
+            }
+        } else {
+            Ok(Rule::Default(str.to_string()))
+        }
+    }
+}
+
+impl Rule {
+    fn destination(&self) -> &str {
+        match self {
+            Rule::Lt(_, _, destination)
+            | Rule::Gt(_, _, destination)
+            | Rule::Default(destination) => destination,
+        }
+    }
+}
+
+/// Every named workflow and its rules, in order.
+#[derive(Debug)]
+pub struct Workflow {
+    pub steps: HashMap<String, Vec<Rule>>,
+}
+
+// Splits a `px{a<2006:qkq,m>2090:A,rfg}` line into its name and rules
+fn split_workflow(line: &str) -> Result<(&str, &str), ParseError> {
+    line.strip_suffix('}')
+        .and_then(|line| line.split_once('{'))
+        .filter(|(name, _)| !name.is_empty())
+        .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidWorkflow, line, line))
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    /// Parses one workflow per line, checking every rule leads to an existing
+    /// workflow or a decision.
+    fn from_str(document: &str) -> Result<Self, Self::Err> {
+        let names = aoc_core::parse_lines(document, |line| Ok(split_workflow(line)?.0))?;
+        let names: HashSet<&str> = names.into_iter().collect();
+
+        let steps = aoc_core::parse_lines(document, |line| {
+            let (key, rules) = split_workflow(line)?;
+            let rules = rules
+                .split(',')
+                .map(|rule_str| {
+                    let rule = Rule::try_from(rule_str).map_err(|e| e.within(line, rule_str))?;
+                    let destination = rule.destination();
+
+                    if !Self::is_decision(destination) && !names.contains(destination) {
+                        let text = &rule_str[rule_str.len() - destination.len()..];
+                        return Err(ParseError::new(ParseErrorKind::UnknownWorkflow, line, text));
+                    }
+
+                    Ok(rule)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((key.to_string(), rules))
+        })?;
+
+        if !names.contains(ENTRANCE) {
+            return Err(ParseError::new(ParseErrorKind::MissingEntrance, document, ""));
+        }
+
+        Ok(Workflow { steps: steps.into_iter().collect() })
+    }
+}
+
+impl Workflow {
+    fn is_decision(destination: &str) -> bool {
+        destination == ACCEPTED || destination == REJECTED
+    }
+
+    /// Whether a part ends up accepted when starting at the `in` workflow.
+    pub fn is_accepted(&self, input: &Input) -> bool {
+        let mut destination = String::from(ENTRANCE);
+
+        while !Self::is_decision(&destination) {
+            let rules = self.steps.get(&destination).unwrap();
+
+            for rule in rules {
+                match rule {
+                    Rule::Lt(key, value, new_destination) => {
+                        if input.values.get(key).unwrap() < value {
+                            destination = new_destination.clone();
+                            break;
+                        }
+                    }
+                    Rule::Gt(key, value, new_destination) => {
+                        if input.values.get(key).unwrap() > value {
+                            destination = new_destination.clone();
+                            break;
+                        }
+                    }
+                    Rule::Default(new_destination) => {
+                        destination = new_destination.clone();
+                    }
+                }
+            }
+        }
+
+        destination == ACCEPTED
+    }
+
+    /// Number of distinct rating combinations that would be accepted.
+    pub fn accepted_combinations(&self) -> u64 {
+        self.walk_possibilities(String::from(ENTRANCE), InputRange::default())
+    }
+
+    fn walk_possibilities(&self, rule_key: String, mut input_range: InputRange) -> u64 {
+        let mut sum = 0_u64;
+        let rules = self.steps.get(&rule_key).unwrap();
+
+        for rule in rules {
+            match rule {
+                Rule::Lt(key, value, new_destination) => {
+                    let (current_range, next_range) = input_range.create_lt_branches(key, value);
+                    input_range = next_range;
+
+                    sum += match new_destination.as_str() {
+                        ACCEPTED => current_range.possibility_count(),
+                        REJECTED => 0,
+                        _ => self.walk_possibilities(new_destination.clone(), current_range),
+                    }
+                }
+                Rule::Gt(key, value, new_destination) => {
+                    let (current_range, next_range) = input_range.create_gt_branches(key, value);
+                    input_range = next_range;
+
+                    sum += match new_destination.as_str() {
+                        ACCEPTED => current_range.possibility_count(),
+                        REJECTED => 0,
+                        _ => self.walk_possibilities(new_destination.clone(), current_range),
+                    }
+                }
+                Rule::Default(new_destination) => {
+                    let current_range = input_range.clone();
+
+                    sum += match new_destination.as_str() {
+                        ACCEPTED => current_range.possibility_count(),
+                        REJECTED => 0,
+                        _ => self.walk_possibilities(new_destination.clone(), current_range),
+                    }
+                }
+            }
+        }
+
+        sum
+    }
+}
+
+/// The ratings of a single machine part.
+#[derive(Debug)]
+pub struct Input {
+    pub values: HashMap<char, u32>,
+}
+
+impl TryFrom<&str> for Input {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let s = line
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidPart, line, line))?;
+
+        let values: HashMap<char, u32> = s
+            .split(',')
+            .map(|str| {
+                let (key, value) = str
+                    .split_once('=')
+                    .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidPart, line, str))?;
+                Ok((parse_rating(line, key)?, parse_value(line, value)?))
+            })
+            .collect::<Result<_, _>>()?;
+
+        if let Some(rating) = RATINGS.iter().find(|rating| !values.contains_key(rating)) {
+            return Err(ParseError::new(ParseErrorKind::MissingRating(*rating), line, line));
+        }
+
+        Ok(Input { values })
+    }
+}
+
+impl Input {
+    /// Sum of all ratings.
+    pub fn value(&self) -> u32 {
+        self.values.values().sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct InputRange {
+    inputs: HashMap<char, std::ops::Range<u16>>,
+}
+
+impl InputRange {
+    fn default() -> Self {
+        Self {
+            inputs: HashMap::from([
+                ('x', 1..4001),
+                ('m', 1..4001),
+                ('a', 1..4001),
+                ('s', 1..4001),
+            ]),
+        }
+    }
+
+    fn possibility_count(&self) -> u64 {
+        self.inputs
+            .values()
+            .map(|v| v.len() as u64)
+            .product::<u64>()
+    }
+
+    fn create_lt_branches(&self, key: &char, value: &u32) -> (Self, Self) {
+        let mut current_range = self.clone();
+        let mut next_range = self.clone();
+
+        let current_key_range = current_range.inputs.get_mut(key).unwrap();
+        *current_key_range = current_key_range.start..current_key_range.end.min(*value as u16);
+
+        let next_key_range = next_range.inputs.get_mut(key).unwrap();
+        *next_key_range = (*value as u16)..next_key_range.end;
+
+        (current_range, next_range)
+    }
+
+    fn create_gt_branches(&self, key: &char, value: &u32) -> (Self, Self) {
+        let mut current_range = self.clone();
+        let mut next_range = self.clone();
+
+        let current_key_range = current_range.inputs.get_mut(key).unwrap();
+        *current_key_range = (*value as u16 + 1)..current_key_range.end;
+
+        let next_key_range = next_range.inputs.get_mut(key).unwrap();
+        *next_key_range = next_key_range.start..(*value as u16 + 1);
+
+        (current_range, next_range)
+    }
+}
+
+/// Parses the workflows and the parts listed after them.
+pub fn parse_document(document: &str) -> Result<(Workflow, Vec<Input>), ParseError> {
+    let (rules, inputs) = document
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new(ParseErrorKind::MissingParts, document, ""))?;
+    let workflow = Workflow::from_str(rules)?;
+    let inputs = aoc_core::parse_lines(inputs, Input::try_from)
+        .map_err(|e| e.below(rules.lines().count() + 1))?;
+
+    Ok((workflow, inputs))
+}
+
+/// Sum of the ratings of all accepted parts.
+pub fn part1((workflow, inputs): &(Workflow, Vec<Input>)) -> u32 {
+    inputs
+        .iter()
+        .filter(|i| workflow.is_accepted(i))
+        .map(|i| i.value())
+        .sum::<u32>()
+}
+
+/// Number of rating combinations, each from 1 to 4000, that are accepted.
+pub fn part2((workflow, _inputs): &(Workflow, Vec<Input>)) -> u64 {
+    workflow.accepted_combinations()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_input() {
+        let input = Input::try_from("{x=1679,m=44,a=2067,s=496}").unwrap();
+
+        assert_eq!(input.values.get(&'x'), Some(&1679));
+        assert_eq!(input.values.get(&'m'), Some(&44));
+        assert_eq!(input.values.get(&'a'), Some(&2067));
+        assert_eq!(input.values.get(&'s'), Some(&496));
+    }
+
+    #[test]
+    fn test_rule_from() {
+        assert_eq!(
+            Rule::try_from("x<1679:px").unwrap(),
+            Rule::Lt('x', 1679, String::from("px"))
+        );
+        assert_eq!(
+            Rule::try_from("x>1679:px").unwrap(),
+            Rule::Gt('x', 1679, String::from("px"))
+        );
+        assert_eq!(Rule::try_from("px").unwrap(), Rule::Default(String::from("px")))
+    }
+
+    #[test]
+    fn test_rule_try_from_errors() {
+        let error = Rule::try_from("q<1679:px").unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidRating, 1));
+
+        let error = Rule::try_from("x=1679:px").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidRule);
+
+        let error = Rule::try_from("x<16a9:px").unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidValue, 3, "16a9"));
+    }
+
+    #[test]
+    fn test_parse_document_errors() {
+        let error = parse_document("in{x<10:px,A}\npx{R}").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingParts);
+
+        let error = parse_document("in{x<10:qq,A}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::UnknownWorkflow, 1, 9));
+
+        let error = parse_document("px{R}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingEntrance);
+
+        let error = parse_document("in{A}\n\n{x=1,m=2,a=3,s=4}\n{x=1,m=2,a=3}").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::MissingRating('s'), 4));
+    }
+
+    #[test]
+    fn test_create_lt_branches() {
+        let input_range = InputRange::default();
+        let (current_range, next_range) = input_range.create_lt_branches(&'x', &1679);
+
+        assert_eq!(current_range.inputs.get(&'x'), Some(&(1..1679)));
+        assert_eq!(next_range.inputs.get(&'x'), Some(&(1679..4001)));
+    }
+
+    #[test]
+    fn test_create_gt_branches() {
+        let input_range = InputRange::default();
+        let (current_range, next_range) = input_range.create_gt_branches(&'x', &1679);
+
+        assert_eq!(current_range.inputs.get(&'x'), Some(&(1680..4001)));
+        assert_eq!(next_range.inputs.get(&'x'), Some(&(1..1680)));
+    }
+}
@@ -0,0 +1,13 @@
+use aoc_core::{Answer, Solution};
+use day19_workflows::Day19;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day19.part1(EXAMPLE).unwrap(), Answer::Number(19114));
+    assert_eq!(
+        Day19.part2(EXAMPLE).unwrap(),
+        Answer::Number(167409079868000)
+    );
+}
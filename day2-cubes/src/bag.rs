@@ -0,0 +1,203 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::color::Color;
+
+/// The cubes in the bag, by colour. Colours that aren't listed have no
+/// cubes.
+///
+/// Bags are equal when they hold as many cubes of every colour, whatever
+/// order the colours are listed in.
+#[derive(Debug, Clone)]
+pub struct Bag {
+    cubes: Vec<(Color, i32)>,
+}
+
+/// The bag the puzzle asks about: 12 red, 13 green and 14 blue cubes.
+pub const STANDARD_BAG: [(Color, i32); 3] =
+    [(Color::RED, 12), (Color::GREEN, 13), (Color::BLUE, 14)];
+
+impl Default for Bag {
+    fn default() -> Self {
+        Self::new(STANDARD_BAG)
+    }
+}
+
+impl Bag {
+    /// A bag of the given cubes, keeping the order of the colours. A colour
+    /// listed twice keeps its last count.
+    pub fn new(cubes: impl IntoIterator<Item = (Color, i32)>) -> Self {
+        let mut bag = Self { cubes: Vec::new() };
+        for (color, count) in cubes {
+            bag.set(color, count);
+        }
+
+        bag
+    }
+
+    /// Number of `color` cubes in the bag.
+    pub fn count(&self, color: Color) -> i32 {
+        self.cubes
+            .iter()
+            .find(|&&(c, _)| c == color)
+            .map_or(0, |&(_, count)| count)
+    }
+
+    pub fn set(&mut self, color: Color, count: i32) {
+        match self.cubes.iter_mut().find(|(c, _)| *c == color) {
+            Some((_, c)) => *c = count,
+            None => self.cubes.push((color, count)),
+        }
+    }
+
+    /// The colours in the bag with their counts.
+    pub fn cubes(&self) -> impl Iterator<Item = (Color, i32)> + '_ {
+        self.cubes.iter().copied()
+    }
+
+    /// Product of the number of cubes of every colour, saturating at
+    /// `i64::MAX`.
+    pub fn power(&self) -> i64 {
+        self.cubes
+            .iter()
+            .fold(1, |product, &(_, count)| product.saturating_mul(count.into()))
+    }
+
+    /// Parses a bag from a TOML table of colours and counts:
+    ///
+    /// ```toml
+    /// red = 12
+    /// green = 13
+    /// blue = 14
+    /// ```
+    pub fn from_toml(contents: &str) -> Result<Self, String> {
+        let table: toml::Table = contents
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+        let cubes = table
+            .into_iter()
+            .map(|(color, count)| match count {
+                toml::Value::Integer(n) if n >= 0 => i32::try_from(n)
+                    .map(|n| (Color::new(&color), n))
+                    .map_err(|_| format!("{} has too many cubes", color)),
+                _ => Err(format!("{} should be a number of cubes", color)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(cubes))
+    }
+
+    /// Loads a bag from a TOML file, see [`Bag::from_toml`].
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        Self::from_toml(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+impl PartialEq for Bag {
+    fn eq(&self, other: &Self) -> bool {
+        self.cubes()
+            .chain(other.cubes())
+            .all(|(color, _)| self.count(color) == other.count(color))
+    }
+}
+
+impl Eq for Bag {}
+
+/// Parses `red=12,green=13,blue=14`.
+impl FromStr for Bag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cubes = s
+            .split(',')
+            .map(|cubes| {
+                let (color, count) = cubes
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected <colour>=<count>, got {:?}", cubes))?;
+                let color = color.trim();
+                let count = count
+                    .trim()
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|count| i32::try_from(count).ok())
+                    .ok_or_else(|| format!("invalid number of {} cubes: {:?}", color, count))?;
+
+                match color {
+                    "" => Err(format!("missing colour in {:?}", cubes)),
+                    color => Ok((Color::new(color), count)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(cubes))
+    }
+}
+
+/// Writes the bag the way it's parsed, e.g. `red=12,green=13,blue=14`.
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (color, count)) in self.cubes().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}={}", color, count)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let bag: Bag = "red=12, green=13,blue=14,yellow=5".parse().unwrap();
+        assert_eq!(bag.count(Color::new("yellow")), 5);
+        assert_eq!(bag.count(Color::new("purple")), 0);
+        assert_eq!(bag.to_string(), "red=12,green=13,blue=14,yellow=5");
+
+        assert!("red".parse::<Bag>().is_err());
+        assert!("red=many".parse::<Bag>().is_err());
+        assert!("red=-1".parse::<Bag>().is_err());
+        assert!("=3".parse::<Bag>().is_err());
+    }
+
+    #[test]
+    fn test_from_toml() {
+        let bag = Bag::from_toml("red = 12\ngreen = 13\nblue = 14").unwrap();
+        assert_eq!((bag.count(Color::RED), bag.count(Color::BLUE)), (12, 14));
+
+        assert!(Bag::from_toml("red = -1").is_err());
+        assert!(Bag::from_toml("red = \"12\"").is_err());
+    }
+
+    #[test]
+    fn test_eq() {
+        let bag: Bag = "blue=14,green=13,red=12".parse().unwrap();
+        assert_eq!(bag, Bag::default());
+
+        let bag: Bag = "red=12,green=13,blue=14,yellow=0".parse().unwrap();
+        assert_eq!(bag, Bag::default());
+
+        let bag: Bag = "red=12,green=13".parse().unwrap();
+        assert_ne!(bag, Bag::default());
+        assert_ne!(Bag::default(), bag);
+    }
+
+    #[test]
+    fn test_power() {
+        assert_eq!(Bag::default().power(), 12 * 13 * 14);
+
+        let bag: Bag = "red=2147483647,green=2147483647,blue=2".parse().unwrap();
+        assert_eq!(bag.power(), 2147483647 * 2147483647 * 2);
+        let bag: Bag = "red=2147483647,green=2147483647,blue=2147483647".parse().unwrap();
+        assert_eq!(bag.power(), i64::MAX);
+    }
+}
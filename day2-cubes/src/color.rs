@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The colour of a cube.
+///
+/// Names are interned: every distinct name is stored once for the lifetime
+/// of the program, so colours are `Copy`, can be sent across threads, and
+/// games don't borrow the text they were parsed from.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Color(&'static str);
+
+impl Color {
+    pub const RED: Color = Color("red");
+    pub const GREEN: Color = Color("green");
+    pub const BLUE: Color = Color("blue");
+
+    /// The colour called `name`.
+    pub fn new(name: &str) -> Self {
+        static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+        let mut names = NAMES
+            .get_or_init(|| Mutex::new(HashSet::from(["red", "green", "blue"])))
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        match names.get(name) {
+            Some(&name) => Color(name),
+            None => {
+                let name: &'static str = Box::leak(name.into());
+                names.insert(name);
+                Color(name)
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl From<&str> for Color {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.0)
+    }
+}
+
+impl fmt::Debug for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Color::new(&name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interning() {
+        let yellow = Color::new("yellow");
+        assert_eq!(yellow, Color::from(String::from("yellow").as_str()));
+        assert!(std::ptr::eq(yellow.as_str(), Color::new("yellow").as_str()));
+
+        assert_eq!(Color::new("red"), Color::RED);
+        assert_ne!(yellow, Color::BLUE);
+        assert_eq!(yellow.to_string(), "yellow");
+    }
+}
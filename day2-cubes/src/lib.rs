@@ -0,0 +1,393 @@
+//! Day 2: Cube Conundrum
+//!
+//! An elf grabs handfuls of coloured cubes from a bag; every game records
+//! the handfuls (turns) that were shown.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::{cmp, fmt};
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+use serde::{Deserialize, Serialize};
+
+mod bag;
+mod color;
+pub mod stats;
+
+pub use bag::{Bag, STANDARD_BAG};
+pub use color::Color;
+
+#[derive(Default)]
+pub struct Day2 {
+    /// The bag games have to be possible with in part 1, the standard one by
+    /// default.
+    pub bag: Bag,
+}
+
+impl Solution for Day2 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn name(&self) -> &'static str {
+        "cubes"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(possible_ids(&process_document(input)?, &self.bag).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&process_document(input)?).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(
+            process_document(input)?,
+            |games| possible_ids(games, &self.bag),
+            |games| part2(games),
+        )))
+    }
+}
+
+/// Sum of the ids of all games that are possible with the standard bag.
+pub fn part1(games: &[Game]) -> i32 {
+    possible_ids(games, &Bag::default())
+}
+
+/// Sum of the ids of all games that are possible with `bag`.
+pub fn possible_ids(games: &[Game], bag: &Bag) -> i32 {
+    games
+        .iter()
+        .filter(|game| game.possible_with(bag))
+        .fold(0, |sum, game| sum + game.id)
+}
+
+/// Sum of the power of the minimal bag of every game, saturating at
+/// `i64::MAX`.
+pub fn part2(games: &[Game]) -> i64 {
+    games
+        .iter()
+        .fold(0, |sum: i64, game| sum.saturating_add(game.power()))
+}
+
+/// The cubes shown in a single turn, by colour.
+pub type Turn = BTreeMap<Color, i32>;
+
+/// A single game: its id and the cubes shown per turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Game {
+    pub id: i32,
+    pub turns: Vec<Turn>,
+}
+
+/// A turn showing more cubes of a colour than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Index of the turn in [`Game::turns`].
+    pub turn: usize,
+    pub color: Color,
+    pub shown: i32,
+    pub in_bag: i32,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "turn {} shows {} {}, but the bag only has {}",
+            self.turn + 1,
+            self.shown,
+            self.color,
+            self.in_bag
+        )
+    }
+}
+
+impl Game {
+    /// Whether every turn fits in a bag of 12 red, 13 green and 14 blue cubes.
+    pub fn possible(&self) -> bool {
+        self.possible_with(&Bag::default())
+    }
+
+    /// Whether every turn fits in `bag`.
+    pub fn possible_with(&self, bag: &Bag) -> bool {
+        self.violation(bag).is_none()
+    }
+
+    /// The first turn showing more cubes of some colour than `bag` holds,
+    /// if any, going by colour name within a turn. Colours missing from the
+    /// bag have no cubes in it.
+    pub fn violation(&self, bag: &Bag) -> Option<Violation> {
+        self.turns.iter().enumerate().find_map(|(turn, cubes)| {
+            cubes.iter().find_map(|(&color, &shown)| {
+                let in_bag = bag.count(color);
+                (shown > in_bag).then_some(Violation {
+                    turn,
+                    color,
+                    shown,
+                    in_bag,
+                })
+            })
+        })
+    }
+
+    /// The smallest bag this game is possible with: the most cubes shown of
+    /// every colour in any turn, colours sorted by name.
+    pub fn minimal_bag(&self) -> Bag {
+        let mut cubes = Turn::new();
+        for turn in &self.turns {
+            for (&color, &count) in turn {
+                let max = cubes.entry(color).or_default();
+                *max = cmp::max(*max, count);
+            }
+        }
+
+        Bag::new(cubes)
+    }
+
+    /// Product of the fewest red, green and blue cubes that make this game
+    /// possible, saturating at `i64::MAX`.
+    pub fn power(&self) -> i64 {
+        let minimal_bag = self.minimal_bag();
+        let standard = STANDARD_BAG.iter().map(|&(color, _)| (color, minimal_bag.count(color)));
+        Bag::new(standard).power()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingHeader,
+    InvalidId,
+    InvalidCubes,
+    InvalidCount,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingHeader => write!(f, "expected \"Game <id>:\""),
+            ParseErrorKind::InvalidId => write!(f, "invalid game id"),
+            ParseErrorKind::InvalidCubes => write!(f, "expected \"<count> <colour>\""),
+            ParseErrorKind::InvalidCount => write!(f, "invalid cube count"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// Parses every line of the document into a [`Game`].
+pub fn process_document(document: &str) -> Result<Vec<Game>, ParseError> {
+    aoc_core::parse_lines(document, process_line)
+}
+
+/// Parses a single `Game 1: 3 blue, 4 red; 1 red` line.
+pub fn process_line(line: &str) -> Result<Game, ParseError> {
+    line.parse()
+}
+
+/// Parses a single `Game 1: 3 blue, 4 red; 1 red` line, without any
+/// regular expressions.
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let error = |kind, text| ParseError::new(kind, line, text);
+
+        let (id, turns) = line
+            .strip_prefix("Game ")
+            .and_then(|rest| rest.split_once(':'))
+            .ok_or_else(|| error(ParseErrorKind::MissingHeader, line))?;
+        let id: i32 = id.parse().map_err(|_| error(ParseErrorKind::InvalidId, id))?;
+
+        let mut game = Game {
+            id,
+            turns: Vec::new(),
+        };
+
+        for turn_str in turns.split(';') {
+            let mut turn = Turn::new();
+            for cubes in turn_str.split(',').map(str::trim) {
+                let (count, color) = cubes
+                    .split_once(' ')
+                    .filter(|(_, color)| color.trim() == *color)
+                    .ok_or_else(|| error(ParseErrorKind::InvalidCubes, cubes))?;
+                let count = count
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|count| i32::try_from(count).ok())
+                    .ok_or_else(|| error(ParseErrorKind::InvalidCount, count))?;
+                turn.insert(Color::new(color), count);
+            }
+
+            game.turns.push(turn);
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_line() {
+        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let game = process_line(line).unwrap();
+
+        assert_eq!(game.id, 1);
+
+        assert_eq!(game.turns.len(), 3);
+        assert_eq!(
+            game.turns.iter().map(|t| t.len()).collect::<Vec<_>>(),
+            [2, 3, 1]
+        );
+
+        assert_eq!(game.turns[0].get(&Color::BLUE), Some(&3));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let game: Game = "Game 7: 3 blue, 4 red; 2 yellow".parse().unwrap();
+        assert_eq!(game.turns[1].get(&Color::new("yellow")), Some(&2));
+        assert!("Game 7 3 blue".parse::<Game>().is_err());
+
+        // Games own their colours, so they outlive the line and can be sent
+        let handle = std::thread::spawn(move || game.minimal_bag().to_string());
+        assert_eq!(handle.join().unwrap(), "blue=3,red=4,yellow=2");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let toml = "id = 3\nturns = [{ red = 3, blue = 1 }, { green = 2 }]";
+        let game: Game = toml::from_str(toml).unwrap();
+        assert_eq!(game, process_line("Game 3: 1 blue, 3 red; 2 green").unwrap());
+    }
+
+    #[test]
+    fn test_process_document() {
+        let document = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let games = process_document(document).unwrap();
+
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].id, 1);
+        assert_eq!(games[1].id, 2);
+    }
+
+    #[test]
+    fn test_process_line_errors() {
+        let error = process_line("Gme 1: 3 blue").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingHeader);
+
+        let error = process_line("Game x: 3 blue").unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidId, 6, "x"));
+
+        let error = process_line("Game 1: 3 blue, many red").unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidCount, 17, "many"));
+
+        let error = process_line("Game 1: 3 blue; red").unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidCubes, 17, "red"));
+
+        let error = process_line("Game 1: 3 blue, -3 red").unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidCount, 17, "-3"));
+
+        let error = process_line("Game 1: 3 blue, 3  red").unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidCubes, 17, "3  red"));
+
+        let error = process_line("Game 1: 3 blue, 3 ").unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidCubes, 17));
+    }
+
+    #[test]
+    fn test_process_document_reports_line() {
+        let document = "Game 1: 3 blue\nGame 2: 3 blue,";
+        let error = process_document(document).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 16));
+    }
+
+    #[test]
+    fn test_possible_with_possible() {
+        let possible_game = Game {
+            id: 1,
+            turns: vec![
+                [(Color::RED, 12), (Color::GREEN, 13), (Color::BLUE, 14)]
+                    .iter()
+                    .cloned()
+                    .collect(),
+                [(Color::RED, 12), (Color::BLUE, 14)].iter().cloned().collect(),
+                [(Color::RED, 10), (Color::GREEN, 13), (Color::BLUE, 14)]
+                    .iter()
+                    .cloned()
+                    .collect(),
+            ],
+        };
+
+        assert!(possible_game.possible());
+    }
+
+    #[test]
+    fn test_possible_with_impossible() {
+        let impossible_game = Game {
+            id: 1,
+            turns: vec![
+                [(Color::RED, 12), (Color::GREEN, 13), (Color::BLUE, 14)]
+                    .iter()
+                    .cloned()
+                    .collect(),
+                [(Color::RED, 12), (Color::GREEN, 13), (Color::BLUE, 15)]
+                    .iter()
+                    .cloned()
+                    .collect(),
+                [(Color::RED, 12), (Color::GREEN, 13), (Color::BLUE, 13)]
+                    .iter()
+                    .cloned()
+                    .collect(),
+            ],
+        };
+
+        assert!(!impossible_game.possible());
+    }
+
+    #[test]
+    fn test_power() {
+        let game = Game {
+            id: 1,
+            turns: vec![
+                [(Color::RED, 1), (Color::BLUE, 12)] .iter() .cloned() .collect(),
+                [(Color::RED, 10), (Color::GREEN, 13), (Color::BLUE, 15)] .iter() .cloned() .collect(),
+                [(Color::RED, 5), (Color::GREEN, 13), (Color::BLUE, 13)] .iter() .cloned() .collect(),
+            ],
+        };
+
+        assert_eq!(game.power(), 10 * 15 * 13);
+    }
+
+    #[test]
+    fn test_minimal_bag() {
+        let game = process_line("Game 1: 3 blue, 4 red; 1 red, 2 yellow, 6 blue; 2 green").unwrap();
+        assert_eq!(game.minimal_bag().to_string(), "blue=6,green=2,red=4,yellow=2");
+
+        // Without any green, no standard bag is minimal
+        let game = process_line("Game 1: 3 blue, 4 red").unwrap();
+        assert_eq!(game.power(), 0);
+    }
+
+    #[test]
+    fn test_violation() {
+        let game = process_line("Game 1: 3 blue, 4 red; 1 red, 2 yellow, 6 blue").unwrap();
+        let bag: Bag = "red=4,blue=6,yellow=1".parse().unwrap();
+        let violation = game.violation(&bag).unwrap();
+
+        assert_eq!((violation.turn, violation.color.as_str()), (1, "yellow"));
+        assert_eq!(violation.to_string(), "turn 2 shows 2 yellow, but the bag only has 1");
+
+        assert!(game.possible_with(&game.minimal_bag()));
+        assert!(!game.possible());
+    }
+}
@@ -1,171 +1,145 @@
-use std::collections::HashMap;
-use std::cmp;
-
-use regex::Regex;
-
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        println!("Usage: cubes <filename>");
-        return;
-    }
-
-    let filename = &args[1];
-    let document =
-        std::fs::read_to_string(filename).expect("Something went wrong reading the file");
-
-    let games = process_document(&document);
-    let sum = games
-        .iter()
-        .filter(|game| game.possible())
-        .fold(0, |sum, game| sum + game.id);
-
-    let power = games.iter().fold(0, |sum, game| sum + game.power());
-
-    println!("Sum of possible games: {}", sum);
-    println!("Power of all games: {}", power);
+use std::path::Path;
+
+use aoc_core::cli::{for_each_file, read_input, Options};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
+use cubes::stats::{color_stats, estimate_bag, log_likelihood, total_cubes, turn_probability};
+use cubes::{process_document, Bag, Day2, Game};
+
+#[derive(Parser)]
+#[command(subcommand_negates_reqs = true)]
+struct Args {
+    #[command(flatten)]
+    options: Options,
+
+    /// Cubes in the bag for part 1, as <colour>=<count> pairs
+    #[arg(long, global = true, default_value_t = Bag::default(), conflicts_with = "bag_file")]
+    bag: Bag,
+
+    /// TOML file of colours and their number of cubes, instead of --bag
+    #[arg(long, global = true, value_name = "FILE")]
+    bag_file: Option<String>,
+
+    /// Show the minimal bag of every game, and the first turn the bag can't
+    /// hold, instead of solving
+    #[arg(long, conflicts_with_all = ["bench", "check", "format"])]
+    report: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-struct Game<'g> {
-    id: i32,
-    turns: Vec<HashMap<&'g str, i32>>,
+#[derive(Subcommand)]
+enum Command {
+    /// Show how many cubes of every colour the games show, the bag they were
+    /// most likely drawn from, and how likely the bag is to give every turn
+    Stats {
+        #[arg(required = true, value_name = "FILE")]
+        files: Vec<String>,
+
+        /// Most cubes the estimated bag may hold
+        #[arg(long, default_value_t = 1000)]
+        max_cubes: i32,
+    },
 }
 
-const POSSIBLE_CONTENTS: [(&str, i32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
-impl<'g> Game<'g> {
-    fn possible(&self) -> bool {
-        self.turns.iter().all(|turn| {
-            POSSIBLE_CONTENTS
-                .iter()
-                .all(|(color, count)| turn.get(color).unwrap_or(&0) <= count)
-        })
+fn main() {
+    let args: Args = aoc_core::cli::parse(&Day2::default());
+    let bag = match &args.bag_file {
+        Some(path) => Bag::load(Path::new(path)).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }),
+        None => args.bag,
+    };
+    // The recorded answers are for the standard bag. The options are global
+    // for the stats subcommand, which has no --check to conflict with.
+    if args.options.check && bag != Bag::default() {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--check can't be used with a bag other than the standard one",
+            )
+            .exit();
     }
 
-    fn power(&self) -> i32 {
-        POSSIBLE_CONTENTS.iter().fold(1, |power, (color, _)| {
-            self.turns
-                .iter()
-                .fold(0,|max, turn| 
-                    cmp::max(*turn.get(color).unwrap_or(&0), max)
-                ) * power
+    let failed = if let Some(Command::Stats { files, max_cubes }) = &args.command {
+        for_each_file(files, true, |filename| {
+            stats_file(filename, &bag, *max_cubes)
         })
-    }
-}
+    } else if args.report {
+        for_each_file(&args.options.files, true, |filename| {
+            report_file(filename, &bag)
+        })
+    } else {
+        aoc_core::cli::run(&Day2 { bag }, &args.options);
+        0
+    };
 
-fn process_document(document: &str) -> Vec<Game> {
-    document.lines().map(|line| process_line(line)).collect()
+    if failed > 0 {
+        std::process::exit(1);
+    }
 }
 
-fn process_line(line: &str) -> Game {
-    let regex: Regex = Regex::new(r"Game (\d+):(.*)").unwrap();
-    let captures = regex.captures(line).unwrap();
-    let id: i32 = captures.get(1).unwrap().as_str().parse().unwrap();
-
-    let mut game = Game {
-        id,
-        turns: Vec::new(),
-    };
-
-    let turns = captures.get(2).unwrap().as_str().split(";");
-    let turn_regex: Regex = Regex::new(r"(\d+) (\w+)").unwrap();
-    for turn_str in turns {
-        let mut turn: HashMap<&str, i32> = HashMap::new();
-        for captures in turn_regex.captures_iter(turn_str) {
-            let count: i32 = captures.get(1).unwrap().as_str().parse().unwrap();
-            let color: &str = captures.get(2).unwrap().as_str();
-            turn.insert(color, count);
+/// Prints a line per game.
+fn report_file(filename: &str, bag: &Bag) -> Result<(), String> {
+    let document = read_input(filename)?;
+    let games = process_document(&document).map_err(|e| format!("{}: {}", filename, e))?;
+
+    for game in games {
+        match game.violation(bag) {
+            Some(violation) => println!(
+                "Game {}: minimal bag {}; {}",
+                game.id,
+                game.minimal_bag(),
+                violation
+            ),
+            None => println!(
+                "Game {}: minimal bag {}; possible",
+                game.id,
+                game.minimal_bag()
+            ),
         }
-
-        game.turns.push(turn);
     }
 
-    game
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process_line() {
-        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
-        let game = process_line(line);
-
-        assert_eq!(game.id, 1);
+/// Prints the max and mean of every colour, the estimated bag, and the
+/// probability of every turn of every game with `bag`.
+fn stats_file(filename: &str, bag: &Bag, max_cubes: i32) -> Result<(), String> {
+    let document = read_input(filename)?;
+    let games = process_document(&document).map_err(|e| format!("{}: {}", filename, e))?;
 
-        assert_eq!(game.turns.len(), 3);
-        assert_eq!(
-            game.turns.iter().map(|t| t.len()).collect::<Vec<_>>(),
-            [2, 3, 1]
-        );
-
-        assert_eq!(game.turns[0].get("blue"), Some(&3));
+    println!("{:<8} {:>4} {:>6}", "colour", "max", "mean");
+    for stats in color_stats(&games) {
+        println!("{}", stats);
     }
 
-    #[test]
-    fn test_process_document() {
-        let document = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
-        let games = process_document(document);
-
-        assert_eq!(games.len(), 2);
-        assert_eq!(games[0].id, 1);
-        assert_eq!(games[1].id, 2);
+    let estimate = estimate_bag(&games, max_cubes);
+    println!(
+        "\nMost likely bag: {} (log-likelihood {:.2})",
+        estimate,
+        log_likelihood(&games, &estimate)
+    );
+    if total_cubes(&estimate) >= i64::from(max_cubes) {
+        println!("The turns don't limit the size of the bag, it holds --max-cubes");
     }
 
-    #[test]
-    fn test_possible_with_possible() {
-        let possible_game = Game {
-            id: 1,
-            turns: vec![
-                [("red", 12), ("green", 13), ("blue", 14)]
-                    .iter()
-                    .cloned()
-                    .collect(),
-                [("red", 12), ("blue", 14)].iter().cloned().collect(),
-                [("red", 10), ("green", 13), ("blue", 14)]
-                    .iter()
-                    .cloned()
-                    .collect(),
-            ],
-        };
-
-        assert!(possible_game.possible());
+    println!("\nTurn probabilities with {}:", bag);
+    for game in &games {
+        println!("Game {}: {}", game.id, turn_probabilities(game, bag));
     }
 
-    #[test]
-    fn test_possible_with_impossible() {
-        let impossible_game = Game {
-            id: 1,
-            turns: vec![
-                [("red", 12), ("green", 13), ("blue", 14)]
-                    .iter()
-                    .cloned()
-                    .collect(),
-                [("red", 12), ("green", 13), ("blue", 15)]
-                    .iter()
-                    .cloned()
-                    .collect(),
-                [("red", 12), ("green", 13), ("blue", 13)]
-                    .iter()
-                    .cloned()
-                    .collect(),
-            ],
-        };
-
-        assert!(!impossible_game.possible());
-    }
+    Ok(())
+}
 
-    #[test]
-    fn test_power() {
-        let game = Game {
-            id: 1,
-            turns: vec![
-                [("red", 1), ("blue", 12)] .iter() .cloned() .collect(),
-                [("red", 10), ("green", 13), ("blue", 15)] .iter() .cloned() .collect(),
-                [("red", 5), ("green", 13), ("blue", 13)] .iter() .cloned() .collect(),
-            ],
-        };
-
-        assert_eq!(game.power(), 10 * 15 * 13);
-    }
+fn turn_probabilities(game: &Game, bag: &Bag) -> String {
+    let probabilities: Vec<String> = game
+        .turns
+        .iter()
+        .map(|turn| format!("{:.2e}", turn_probability(turn, bag)))
+        .collect();
+
+    probabilities.join(" ")
 }
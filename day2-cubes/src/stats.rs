@@ -0,0 +1,242 @@
+//! What the recorded turns say about the bag they were drawn from.
+//!
+//! Every turn is taken as a handful drawn at once, i.e. without replacement,
+//! and put back before the next one. The chance of a turn showing `k` cubes
+//! of every colour from a bag with `n` of them is then hypergeometric:
+//! the product of `C(n, k)` over the colours, divided by `C(total, shown)`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{Bag, Color, Game, Turn};
+
+/// How many cubes of a colour the games show.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorStats {
+    pub color: Color,
+    /// Most cubes of the colour shown in a single turn.
+    pub max: i32,
+    /// Mean over the games of the most cubes shown in any of their turns,
+    /// counting games that never show the colour as 0.
+    pub mean: f64,
+}
+
+/// Max and mean of every colour shown in `games`, sorted by colour name.
+pub fn color_stats(games: &[Game]) -> Vec<ColorStats> {
+    let mut maxima: BTreeMap<Color, Vec<i32>> = BTreeMap::new();
+    for (idx, game) in games.iter().enumerate() {
+        for (color, count) in game.minimal_bag().cubes() {
+            maxima.entry(color).or_insert_with(|| vec![0; games.len()])[idx] = count;
+        }
+    }
+
+    maxima
+        .into_iter()
+        .map(|(color, counts)| {
+            let sum: i32 = counts.iter().sum();
+            ColorStats {
+                color,
+                max: counts.iter().copied().max().unwrap_or(0),
+                mean: f64::from(sum) / games.len() as f64,
+            }
+        })
+        .collect()
+}
+
+/// Probability of drawing exactly `turn` from `bag` in a single handful. A
+/// turn showing more cubes of a colour than the bag holds is impossible.
+pub fn turn_probability(turn: &Turn, bag: &Bag) -> f64 {
+    ln_turn_probability(turn, bag).exp()
+}
+
+/// Natural log of the probability of every turn of `games` being drawn from
+/// `bag`, negative infinity if any of them is impossible.
+pub fn log_likelihood(games: &[Game], bag: &Bag) -> f64 {
+    games
+        .iter()
+        .flat_map(|game| &game.turns)
+        .map(|turn| ln_turn_probability(turn, bag))
+        .sum()
+}
+
+/// Log-likelihood a move has to gain for the estimate to take it: about 1%
+/// more likely turns.
+const TOLERANCE: f64 = 0.01;
+
+/// The bag that makes the turns of `games` most likely, holding at most
+/// `max_cubes` cubes in total.
+///
+/// Starting from the smallest bag every game is possible with, this keeps
+/// adding, removing or swapping cubes while that makes the turns noticeably
+/// more likely, see [`TOLERANCE`]. Moves take 1, 2, 4... cubes at a time, up
+/// to the size of the bag, so growing it takes as many moves as doubling it
+/// does. Large draws pull the estimate towards small bags; when the turns are
+/// small compared to the bag the likelihood keeps growing with it, ever more
+/// slowly, and the estimate ends up where growing stops making a difference,
+/// or at `max_cubes`.
+pub fn estimate_bag(games: &[Game], max_cubes: i32) -> Bag {
+    let mut counts: BTreeMap<Color, i32> = BTreeMap::new();
+    for game in games {
+        for (color, count) in game.minimal_bag().cubes() {
+            let max = counts.entry(color).or_default();
+            *max = (*max).max(count);
+        }
+    }
+
+    let minimum = counts.clone();
+    let colors: Vec<Color> = counts.keys().copied().collect();
+    let mut best = Bag::new(counts);
+    let mut best_likelihood = log_likelihood(games, &best);
+
+    loop {
+        let total = total_cubes(&best);
+        let room = i64::from(max_cubes) - total;
+        let steps = std::iter::successors(Some(1), |&step: &i32| step.checked_mul(2))
+            .take_while(|&step| i64::from(step) <= total.max(1));
+
+        let mut moves = Vec::new();
+        for step in steps {
+            for &add in &colors {
+                if i64::from(step) <= room {
+                    moves.push((Some(add), None, step));
+                }
+                moves.push((None, Some(add), step));
+                for &remove in &colors {
+                    if remove != add {
+                        moves.push((Some(add), Some(remove), step));
+                    }
+                }
+            }
+        }
+
+        let neighbours = moves.into_iter().filter_map(|(add, remove, step)| {
+            let mut bag = best.clone();
+            if let Some(color) = add {
+                bag.set(color, bag.count(color).checked_add(step)?);
+            }
+            if let Some(color) = remove {
+                if bag.count(color) - step < minimum[&color] {
+                    return None;
+                }
+                bag.set(color, bag.count(color) - step);
+            }
+            Some(bag)
+        });
+
+        let improvement = neighbours
+            .map(|bag| (log_likelihood(games, &bag), bag))
+            .filter(|(likelihood, _)| *likelihood > best_likelihood + TOLERANCE)
+            .max_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        match improvement {
+            Some((likelihood, bag)) => {
+                best_likelihood = likelihood;
+                best = bag;
+            }
+            None => return best,
+        }
+    }
+}
+
+/// How many cubes `bag` holds in total.
+pub fn total_cubes(bag: &Bag) -> i64 {
+    bag.cubes().map(|(_, count)| i64::from(count)).sum()
+}
+
+fn ln_turn_probability(turn: &Turn, bag: &Bag) -> f64 {
+    let ways: f64 = turn
+        .iter()
+        .map(|(&color, &count)| ln_choose(bag.count(color).into(), count.into()))
+        .sum();
+    if ways == f64::NEG_INFINITY {
+        return ways;
+    }
+
+    let shown: i64 = turn.values().map(|&count| i64::from(count)).sum();
+    ways - ln_choose(total_cubes(bag), shown)
+}
+
+// ln C(n, k), negative infinity when there's no way to choose k out of n
+fn ln_choose(n: i64, k: i64) -> f64 {
+    if k < 0 || k > n {
+        return f64::NEG_INFINITY;
+    }
+
+    let k = k.min(n - k);
+    (0..k).map(|i| ((n - i) as f64 / (i + 1) as f64).ln()).sum()
+}
+
+/// A row of the `stats` table: `blue       15   7.20`.
+impl fmt::Display for ColorStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<8} {:>4} {:>6.2}", self.color, self.max, self.mean)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process_document;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+
+    #[test]
+    fn test_color_stats() {
+        let games = process_document(EXAMPLE).unwrap();
+        let stats = color_stats(&games);
+
+        let blue = &stats[0];
+        assert_eq!((blue.color, blue.max, blue.mean), (Color::BLUE, 6, 5.0));
+        assert_eq!(stats[1].to_string(), "green       3   2.50");
+        assert_eq!(stats.len(), 3);
+    }
+
+    #[test]
+    fn test_turn_probability() {
+        let bag: Bag = "red=2,blue=3".parse().unwrap();
+        let game: Game = "Game 1: 1 red, 1 blue; 2 red; 3 red; 1 green"
+            .parse()
+            .unwrap();
+        let probabilities: Vec<_> = game
+            .turns
+            .iter()
+            .map(|turn| turn_probability(turn, &bag))
+            .collect();
+
+        // 2 * 3 of the 10 ways to draw 2 out of 5, then 1 of the 10
+        assert!((probabilities[0] - 0.6).abs() < 1e-9);
+        assert!((probabilities[1] - 0.1).abs() < 1e-9);
+        assert_eq!(&probabilities[2..], [0.0, 0.0]);
+
+        let bag: Bag = "red=2147483647,green=5".parse().unwrap();
+        let game: Game = "Game 1: 1 green".parse().unwrap();
+        assert!(turn_probability(&game.turns[0], &bag) < 1e-8);
+    }
+
+    #[test]
+    fn test_estimate_bag() {
+        // Drawing everything every time is only likely from exactly that bag
+        let games = process_document("Game 1: 2 red, 1 blue\nGame 2: 2 red, 1 blue").unwrap();
+        let bag = estimate_bag(&games, 100);
+        assert_eq!(bag.to_string(), "blue=1,red=2");
+        assert_eq!(log_likelihood(&games, &bag), 0.0);
+
+        // Single cubes only tell the proportions apart
+        let games = process_document("Game 1: 1 red; 1 red; 1 blue").unwrap();
+        let bag = estimate_bag(&games, 10);
+        assert_eq!(bag.to_string(), "blue=1,red=2");
+
+        let games = process_document("Game 1: 1 red; 1 red; 1 red").unwrap();
+        assert_eq!(estimate_bag(&games, 10).to_string(), "red=1");
+        assert_eq!(estimate_bag(&games, 0).to_string(), "red=1");
+
+        // Small turns barely limit the bag, which stops growing long before
+        // the most cubes it may hold
+        let games = process_document(include_str!("../example.txt")).unwrap();
+        let bag = estimate_bag(&games, i32::MAX);
+        assert_eq!(bag, estimate_bag(&games, 10_000));
+        assert!(total_cubes(&bag) < 1000);
+        assert_eq!(total_cubes(&estimate_bag(&games, 50)), 50);
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use cubes::Day2;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day2::default().part1(EXAMPLE).unwrap(), Answer::Number(8));
+    assert_eq!(Day2::default().part2(EXAMPLE).unwrap(), Answer::Number(2286));
+}
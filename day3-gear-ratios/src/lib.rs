@@ -0,0 +1,98 @@
+//! Day 3: Gear Ratios
+//!
+//! An engine schematic is a grid of numbers and symbols; numbers next to a
+//! symbol are part numbers, and a `*` next to exactly two numbers is a gear.
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+
+pub mod render;
+mod schematic;
+
+pub use schematic::{Number, Schematic};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn name(&self) -> &'static str {
+        "gear-ratios"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&Schematic::from(input)).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&Schematic::from(input)).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(Schematic::from(input), part1, part2)))
+    }
+}
+
+/// Sum of all part numbers, saturating at `u64::MAX`.
+pub fn part1(schematic: &Schematic) -> u64 {
+    schematic.part_numbers().into_iter().fold(0, u64::saturating_add)
+}
+
+/// Sum of all gear ratios, saturating at `u64::MAX`.
+pub fn part2(schematic: &Schematic) -> u64 {
+    schematic.gear_ratios().into_iter().fold(0, u64::saturating_add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gear_ratios() {
+        let document = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        let result = Schematic::from(document).gear_ratios();
+        assert_eq!(result, vec![16345, 451490])
+    }
+
+    #[test]
+    fn test_part_numbers() {
+        let document = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        assert_eq!(Schematic::from(document).part_numbers().iter().sum::<u64>(), 4361)
+    }
+
+    #[test]
+    fn test_totals_saturate() {
+        let document = "99999999999999999999*99999999999999999999
+.........................................
+99999999999999999999*99999999999999999999";
+
+        let schematic = Schematic::from(document);
+        assert_eq!(part1(&schematic), u64::MAX);
+        assert_eq!(part2(&schematic), u64::MAX);
+    }
+}
@@ -0,0 +1,245 @@
+//! Printing a schematic with its numbers and symbols highlighted, to see
+//! which numbers count as part numbers and which `*` are gears.
+
+use std::fmt::Write;
+
+use aoc_grid::Grid;
+
+use crate::schematic::Schematic;
+
+/// What a cell of the schematic is part of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    Empty,
+    /// A number touching a symbol.
+    PartNumber,
+    /// A number touching no symbol.
+    Number,
+    Symbol,
+    /// A `*` touching exactly two numbers.
+    Gear,
+    /// One of the two numbers of a gear.
+    GearPartner,
+}
+
+impl Highlight {
+    const ALL: [Highlight; 5] = [
+        Highlight::PartNumber,
+        Highlight::Number,
+        Highlight::Symbol,
+        Highlight::Gear,
+        Highlight::GearPartner,
+    ];
+
+    fn ansi(&self) -> &'static str {
+        match self {
+            Highlight::Empty => "\x1b[2m",
+            Highlight::PartNumber => "\x1b[32m",
+            Highlight::Number => "\x1b[31m",
+            Highlight::Symbol => "\x1b[1m",
+            Highlight::Gear => "\x1b[1;33m",
+            Highlight::GearPartner => "\x1b[36m",
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            Highlight::Empty => "empty",
+            Highlight::PartNumber => "part",
+            Highlight::Number => "number",
+            Highlight::Symbol => "symbol",
+            Highlight::Gear => "gear",
+            Highlight::GearPartner => "partner",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Highlight::Empty => "empty",
+            Highlight::PartNumber => "part number",
+            Highlight::Number => "not a part number",
+            Highlight::Symbol => "symbol",
+            Highlight::Gear => "gear",
+            Highlight::GearPartner => "gear's part number",
+        }
+    }
+}
+
+/// How to render a schematic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Render {
+    /// Coloured for a terminal
+    Ansi,
+    /// A standalone HTML page
+    Html,
+}
+
+/// What every cell of `schematic` is part of. Gear partners take precedence
+/// over being a part number.
+pub fn highlights(schematic: &Schematic) -> Grid<Highlight> {
+    let mut highlights = schematic.grid().map(|_| Highlight::Empty);
+
+    for number in schematic.numbers() {
+        let highlight = match schematic.is_part_number(number) {
+            true => Highlight::PartNumber,
+            false => Highlight::Number,
+        };
+        for column in number.columns.clone() {
+            highlights[(number.row, column)] = highlight;
+        }
+    }
+
+    for (position, _) in schematic.symbols() {
+        highlights[position] = Highlight::Symbol;
+    }
+
+    for (position, char, numbers) in schematic.symbols_with_neighbors(2) {
+        if char != '*' {
+            continue;
+        }
+
+        highlights[position] = Highlight::Gear;
+        for number in numbers {
+            for column in number.columns.clone() {
+                highlights[(number.row, column)] = Highlight::GearPartner;
+            }
+        }
+    }
+
+    highlights
+}
+
+/// The schematic with every cell highlighted, followed by a legend.
+pub fn render(schematic: &Schematic, render: Render) -> String {
+    match render {
+        Render::Ansi => render_ansi(schematic),
+        Render::Html => render_html(schematic),
+    }
+}
+
+fn render_ansi(schematic: &Schematic) -> String {
+    let mut output = String::new();
+    for (cells, highlights) in schematic.grid().rows().zip(highlights(schematic).rows()) {
+        for (run, highlight) in runs(cells, highlights) {
+            let _ = write!(output, "{}{}\x1b[0m", highlight.ansi(), run);
+        }
+        output.push('\n');
+    }
+
+    output.push('\n');
+    for highlight in Highlight::ALL {
+        let _ = writeln!(
+            output,
+            "{}{}\x1b[0m",
+            highlight.ansi(),
+            highlight.description()
+        );
+    }
+
+    output
+}
+
+fn render_html(schematic: &Schematic) -> String {
+    let mut output = String::from(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Engine schematic</title>
+<style>
+pre { font-family: monospace; }
+.empty { color: #bbb; }
+.part { color: #080; }
+.number { color: #c00; font-weight: bold; }
+.symbol { font-weight: bold; }
+.gear { color: #b80; font-weight: bold; background: #ffd; }
+.partner { color: #08c; }
+</style>
+</head>
+<body>
+<pre>
+",
+    );
+
+    for (cells, highlights) in schematic.grid().rows().zip(highlights(schematic).rows()) {
+        for (run, highlight) in runs(cells, highlights) {
+            let _ = write!(
+                output,
+                "<span class=\"{}\">{}</span>",
+                highlight.class(),
+                escape(&run)
+            );
+        }
+        output.push('\n');
+    }
+
+    output.push_str("</pre>\n<ul>\n");
+    for highlight in Highlight::ALL {
+        let _ = writeln!(
+            output,
+            "<li class=\"{}\">{}</li>",
+            highlight.class(),
+            highlight.description()
+        );
+    }
+    output.push_str("</ul>\n</body>\n</html>\n");
+
+    output
+}
+
+// Consecutive cells with the same highlight, so each run is only styled once
+fn runs(cells: &[char], highlights: &[Highlight]) -> Vec<(String, Highlight)> {
+    let mut runs: Vec<(String, Highlight)> = Vec::new();
+    for (&char, &highlight) in cells.iter().zip(highlights) {
+        match runs.last_mut() {
+            Some((run, last)) if *last == highlight => run.push(char),
+            _ => runs.push((char.to_string(), highlight)),
+        }
+    }
+
+    runs
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlights() {
+        let schematic = Schematic::from("467..114\n...*....\n..35..&.");
+        let highlights = highlights(&schematic);
+
+        assert_eq!(highlights[(0, 0)], Highlight::GearPartner);
+        assert_eq!(highlights[(0, 5)], Highlight::Number);
+        assert_eq!(highlights[(1, 3)], Highlight::Gear);
+        assert_eq!(highlights[(2, 6)], Highlight::Symbol);
+        assert_eq!(highlights[(2, 7)], Highlight::Empty);
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let schematic = Schematic::from("1.\n.#");
+        let output = render(&schematic, Render::Ansi);
+
+        assert!(
+            output.starts_with("\x1b[32m1\x1b[0m\x1b[2m.\x1b[0m\n\x1b[2m.\x1b[0m\x1b[1m#\x1b[0m\n")
+        );
+    }
+
+    #[test]
+    fn test_render_html() {
+        let schematic = Schematic::from("12<\n...");
+        let output = render(&schematic, Render::Html);
+
+        assert!(
+            output.contains("<span class=\"part\">12</span><span class=\"symbol\">&lt;</span>\n")
+        );
+        assert!(output.contains("<span class=\"empty\">...</span>\n</pre>"));
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use aoc_grid::{Grid, Point};
+
+/// A number in the schematic and the cells it covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    /// The number's value, saturating at `u64::MAX`.
+    pub value: u64,
+    pub row: usize,
+    pub columns: Range<usize>,
+}
+
+impl Number {
+    /// Position of the first digit.
+    pub fn start(&self) -> Point {
+        (self.row, self.columns.start)
+    }
+}
+
+/// An engine schematic: a grid of characters, every number in it with its
+/// span, and every symbol by position. Schematics can have any size; short
+/// and empty lines are padded with `.` up to the longest one.
+pub struct Schematic {
+    grid: Grid<char>,
+    /// Sorted by position, so the number covering a cell can be searched for
+    /// rather than indexing every cell.
+    numbers: Vec<Number>,
+    symbols: BTreeMap<Point, char>,
+}
+
+impl From<&str> for Schematic {
+    fn from(document: &str) -> Self {
+        let grid = Grid::parse_padded(document, '.', |c| c);
+
+        let mut numbers = Vec::new();
+        let mut symbols = BTreeMap::new();
+        for (row, cells) in grid.rows().enumerate() {
+            let mut column = 0;
+            while column < cells.len() {
+                let char = cells[column];
+                if !char.is_ascii_digit() {
+                    if char != '.' {
+                        symbols.insert((row, column), char);
+                    }
+                    column += 1;
+                    continue;
+                }
+
+                let start = column;
+                let mut value: u64 = 0;
+                while let Some(digit) = cells.get(column).and_then(|c| c.to_digit(10)) {
+                    value = value.saturating_mul(10).saturating_add(digit.into());
+                    column += 1;
+                }
+
+                numbers.push(Number {
+                    value,
+                    row,
+                    columns: start..column,
+                });
+            }
+        }
+
+        Self {
+            grid,
+            numbers,
+            symbols,
+        }
+    }
+}
+
+impl Schematic {
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Every number, in reading order.
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// Every symbol, anything but a digit or `.`, in reading order.
+    pub fn symbols(&self) -> impl Iterator<Item = (Point, char)> + '_ {
+        self.symbols
+            .iter()
+            .map(|(&position, &char)| (position, char))
+    }
+
+    pub fn symbol_at(&self, position: Point) -> Option<char> {
+        self.symbols.get(&position).copied()
+    }
+
+    /// The number covering `position`, if any.
+    pub fn number_at(&self, position: Point) -> Option<&Number> {
+        self.number_index(position).map(|idx| &self.numbers[idx])
+    }
+
+    fn number_index(&self, (row, column): Point) -> Option<usize> {
+        let idx = self
+            .numbers
+            .partition_point(|number| (number.row, number.columns.end) <= (row, column));
+        let number = self.numbers.get(idx)?;
+
+        (number.row == row && number.columns.contains(&column)).then_some(idx)
+    }
+
+    /// The numbers touching `position`, including diagonally, in reading
+    /// order. A number touching it with several digits is listed once.
+    pub fn numbers_around(&self, position: Point) -> Vec<&Number> {
+        let mut indices: Vec<usize> = self
+            .grid
+            .neighbors_diagonal(position)
+            .filter_map(|neighbor| self.number_index(neighbor))
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices.into_iter().map(|idx| &self.numbers[idx]).collect()
+    }
+
+    /// The symbols touching `number`, including diagonally, in reading order.
+    pub fn symbols_around(&self, number: &Number) -> Vec<(Point, char)> {
+        let rows = number.row.saturating_sub(1)..number.row + 2;
+        let columns = number.columns.start.saturating_sub(1)..number.columns.end + 1;
+
+        rows.flat_map(|row| columns.clone().map(move |column| (row, column)))
+            .filter_map(|position| Some((position, self.symbol_at(position)?)))
+            .collect()
+    }
+
+    /// Whether `number` touches any symbol.
+    pub fn is_part_number(&self, number: &Number) -> bool {
+        !self.symbols_around(number).is_empty()
+    }
+
+    /// Every number touching any symbol, including diagonally.
+    pub fn part_numbers(&self) -> Vec<u64> {
+        self.numbers
+            .iter()
+            .filter(|number| self.is_part_number(number))
+            .map(|number| number.value)
+            .collect()
+    }
+
+    /// Every number touching a `symbol`, in reading order.
+    pub fn numbers_adjacent_to(&self, symbol: char) -> Vec<&Number> {
+        self.numbers
+            .iter()
+            .filter(|number| {
+                self.symbols_around(number)
+                    .iter()
+                    .any(|&(_, char)| char == symbol)
+            })
+            .collect()
+    }
+
+    /// Every symbol touching exactly `count` numbers, with those numbers.
+    pub fn symbols_with_neighbors(&self, count: usize) -> Vec<(Point, char, Vec<&Number>)> {
+        self.symbols()
+            .map(|(position, char)| (position, char, self.numbers_around(position)))
+            .filter(|(_, _, numbers)| numbers.len() == count)
+            .collect()
+    }
+
+    /// The product of the two numbers around every `*` that touches exactly
+    /// two numbers.
+    pub fn gear_ratios(&self) -> Vec<u64> {
+        self.symbols_with_neighbors(2)
+            .into_iter()
+            .filter(|&(_, char, _)| char == '*')
+            .map(|(_, _, numbers)| numbers[0].value.saturating_mul(numbers[1].value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn test_numbers() {
+        let schematic = Schematic::from(EXAMPLE);
+        assert_eq!(schematic.numbers().len(), 10);
+        assert_eq!(
+            schematic.numbers()[1],
+            Number {
+                value: 114,
+                row: 0,
+                columns: 5..8
+            }
+        );
+
+        assert_eq!(schematic.number_at((2, 3)).unwrap().value, 35);
+        assert_eq!(schematic.number_at((2, 4)), None);
+        assert_eq!(schematic.symbol_at((8, 3)), Some('$'));
+    }
+
+    #[test]
+    fn test_numbers_around() {
+        let schematic = Schematic::from(EXAMPLE);
+        let values: Vec<_> = schematic
+            .numbers_around((1, 3))
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, [467, 35]);
+
+        let numbers = schematic.numbers_adjacent_to('#');
+        assert_eq!(numbers.len(), 1);
+        assert_eq!(numbers[0].value, 633);
+    }
+
+    #[test]
+    fn test_symbols_with_neighbors() {
+        let schematic = Schematic::from(EXAMPLE);
+        let lonely: Vec<_> = schematic
+            .symbols_with_neighbors(1)
+            .into_iter()
+            .map(|(position, char, _)| (position, char))
+            .collect();
+        assert_eq!(
+            lonely,
+            [((3, 6), '#'), ((4, 3), '*'), ((5, 5), '+'), ((8, 3), '$')]
+        );
+    }
+
+    #[test]
+    fn test_part_numbers() {
+        let schematic = Schematic::from(EXAMPLE);
+        assert!(!schematic.part_numbers().contains(&114));
+        assert!(!schematic.part_numbers().contains(&58));
+        assert_eq!(schematic.part_numbers().len(), 8);
+    }
+
+    #[test]
+    fn test_borders() {
+        // Numbers in every corner and along every edge, touching symbols
+        // across the border of a schematic that isn't square
+        let schematic = Schematic::from("12*..3\n#...*.\n9...45");
+        let values: Vec<_> = schematic.numbers().iter().map(|n| n.value).collect();
+        assert_eq!(values, [12, 3, 9, 45]);
+        assert_eq!(schematic.part_numbers(), [12, 3, 9, 45]);
+        assert_eq!(schematic.gear_ratios(), [135]);
+
+        let around = schematic.numbers_around((0, 2));
+        assert_eq!(around.iter().map(|n| n.value).collect::<Vec<_>>(), [12]);
+        assert_eq!(schematic.numbers_around((1, 5)).len(), 2);
+        assert_eq!(schematic.number_at((0, 6)), None);
+        assert_eq!(schematic.number_at((3, 0)), None);
+    }
+
+    #[test]
+    fn test_full_width_number() {
+        let schematic = Schematic::from("123\n..*");
+        assert_eq!(schematic.numbers()[0].columns, 0..3);
+        assert_eq!(schematic.part_numbers(), [123]);
+    }
+
+    #[test]
+    fn test_ragged_and_empty_lines() {
+        let schematic = Schematic::from("1\n\n..*77\r\n5");
+        assert_eq!(
+            (schematic.grid().width(), schematic.grid().height()),
+            (5, 4)
+        );
+        assert_eq!(schematic.part_numbers(), [77]);
+        assert_eq!(schematic.symbols_around(&schematic.numbers()[2]).len(), 0);
+
+        let empty = Schematic::from("");
+        assert!(empty.numbers().is_empty() && empty.part_numbers().is_empty());
+        assert_eq!(Schematic::from("\n\n").symbols().count(), 0);
+    }
+
+    #[test]
+    fn test_large_schematic() {
+        let size = 1000;
+        let mut document = vec![".".repeat(size); size];
+        document[0] = format!("{}*", "9".repeat(size - 1));
+        document[size - 1] = format!("#{}", ".".repeat(size - 2)) + "7";
+        let schematic = Schematic::from(document.join("\n").as_str());
+
+        assert_eq!(schematic.numbers()[0].value, u64::MAX);
+        assert_eq!(schematic.part_numbers(), [u64::MAX]);
+        assert_eq!(schematic.number_at((size - 1, size - 1)).unwrap().value, 7);
+        assert!(!schematic.is_part_number(&schematic.numbers()[1]));
+    }
+}
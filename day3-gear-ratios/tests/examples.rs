@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use gear_ratios::Day3;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day3.part1(EXAMPLE).unwrap(), Answer::Number(4361));
+    assert_eq!(Day3.part2(EXAMPLE).unwrap(), Answer::Number(467835));
+}
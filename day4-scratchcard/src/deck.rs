@@ -0,0 +1,141 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::Card;
+
+/// What happens to copies won of cards past the end of the deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Overflow {
+    /// They are never won: a card only copies the cards that follow it.
+    #[default]
+    Clamp,
+    /// Winning them is an error.
+    Error,
+}
+
+/// A card winning copies of more cards than there are after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    pub card: u32,
+    pub win_count: usize,
+    /// How many cards follow it.
+    pub remaining: usize,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "card {} wins copies of the next {} cards, but only {} follow it",
+            self.card, self.win_count, self.remaining
+        )
+    }
+}
+
+impl Error for OverflowError {}
+
+/// The scratchcards, in the order they're played.
+#[derive(Debug, Clone)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    pub fn new(cards: Vec<Card>) -> Self {
+        Self { cards }
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Total score of all cards, saturating at `u64::MAX`.
+    pub fn score(&self) -> u64 {
+        self.cards.iter().map(Card::score).fold(0, u64::saturating_add)
+    }
+
+    /// How many copies of every card there are once every card has won
+    /// copies of the cards after it, counting the original. Counts saturate
+    /// at `u64::MAX`.
+    pub fn copies(&self, overflow: Overflow) -> Result<Vec<u64>, OverflowError> {
+        let mut copies = vec![1u64; self.cards.len()];
+
+        for (idx, card) in self.cards.iter().enumerate() {
+            let win_count = card.win_count();
+            let remaining = self.cards.len() - idx - 1;
+            if win_count > remaining && overflow == Overflow::Error {
+                return Err(OverflowError {
+                    card: card.id,
+                    win_count,
+                    remaining,
+                });
+            }
+
+            let (won, rest) = copies[idx..].split_first_mut().unwrap();
+            for count in rest.iter_mut().take(win_count) {
+                *count = count.saturating_add(*won);
+            }
+        }
+
+        Ok(copies)
+    }
+
+    /// Total number of cards after the cascade, see [`Deck::copies`].
+    pub fn total_cards(&self, overflow: Overflow) -> Result<u64, OverflowError> {
+        let copies = self.copies(overflow)?;
+        Ok(copies.into_iter().fold(0, u64::saturating_add))
+    }
+}
+
+impl From<Vec<Card>> for Deck {
+    fn from(cards: Vec<Card>) -> Self {
+        Self::new(cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deck(win_counts: &[i32]) -> Deck {
+        let cards = win_counts
+            .iter()
+            .zip(1..)
+            .map(|(&wins, id)| Card::new(id, (1..=wins).collect(), (1..=wins).collect()))
+            .collect();
+
+        Deck::new(cards)
+    }
+
+    #[test]
+    fn test_copies() {
+        let deck = deck(&[4, 2, 2, 1, 0, 0]);
+        assert_eq!(deck.copies(Overflow::Error).unwrap(), [1, 2, 4, 8, 14, 1]);
+        assert_eq!(deck.total_cards(Overflow::Error), Ok(30));
+        assert_eq!(deck.score(), 8 + 2 + 2 + 1);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let deck = deck(&[1, 3, 0]);
+        assert_eq!(deck.copies(Overflow::Clamp).unwrap(), [1, 2, 3]);
+
+        let error = deck.copies(Overflow::Error).unwrap_err();
+        assert_eq!((error.card, error.win_count, error.remaining), (2, 3, 1));
+        assert_eq!(
+            error.to_string(),
+            "card 2 wins copies of the next 3 cards, but only 1 follow it"
+        );
+    }
+
+    #[test]
+    fn test_score_saturates() {
+        assert_eq!(deck(&[64, 64]).score(), u64::MAX);
+    }
+
+    #[test]
+    fn test_empty_deck() {
+        let deck = Deck::new(Vec::new());
+        assert_eq!(deck.total_cards(Overflow::Error), Ok(0));
+    }
+}
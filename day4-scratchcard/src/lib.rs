@@ -0,0 +1,280 @@
+//! Day 4: Scratchcards
+//!
+//! Every scratchcard has a list of winning numbers and a list of numbers you
+//! have; matches win points, or copies of the cards below it.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+
+mod deck;
+pub mod simulate;
+
+pub use deck::{Deck, Overflow, OverflowError};
+
+#[derive(Default)]
+pub struct Day4 {
+    /// What happens to copies of cards past the end of the deck in part 2.
+    pub overflow: Overflow,
+}
+
+impl Solution for Day4 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn name(&self) -> &'static str {
+        "scratchcard"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&Deck::from(parse_document(input)?)).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&Deck::from(parse_document(input)?), self.overflow)?.into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(
+            Deck::from(parse_document(input)?),
+            part1,
+            |deck| part2(deck, self.overflow),
+        )))
+    }
+}
+
+/// Parses one [`Card`] per line. Cards have to be numbered consecutively,
+/// since they win copies of the cards right after them.
+pub fn parse_document(document: &str) -> Result<Vec<Card>, ParseError> {
+    let mut cards: Vec<Card> = Vec::new();
+    let mut ids = HashSet::new();
+
+    for (idx, line) in document.lines().enumerate() {
+        let card = Card::from_str(line).map_err(|e| e.below(idx))?;
+
+        let expected = cards.last().map(|previous| previous.id.saturating_add(1));
+        if let Some(expected) = expected.filter(|&expected| expected != card.id) {
+            let kind = match ids.contains(&card.id) {
+                true => ParseErrorKind::DuplicateCard { card: card.id },
+                false => ParseErrorKind::OutOfOrder {
+                    card: card.id,
+                    expected,
+                },
+            };
+            let (id, _) = Card::split_header(line)?;
+            return Err(ParseError::new(kind, line, id).below(idx));
+        }
+
+        ids.insert(card.id);
+        cards.push(card);
+    }
+
+    Ok(cards)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingHeader,
+    InvalidId,
+    MissingSeparator { card: u32 },
+    InvalidNumber { card: u32 },
+    /// A number listed twice on the same side of the card.
+    DuplicateNumber { card: u32 },
+    DuplicateCard { card: u32 },
+    OutOfOrder { card: u32, expected: u32 },
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingHeader => write!(f, "expected \"Card <id>:\""),
+            ParseErrorKind::InvalidId => write!(f, "invalid card id"),
+            ParseErrorKind::MissingSeparator { card } => {
+                write!(f, "card {}: expected \"|\" between the numbers", card)
+            }
+            ParseErrorKind::InvalidNumber { card } => write!(f, "card {}: invalid number", card),
+            ParseErrorKind::DuplicateNumber { card } => {
+                write!(f, "card {}: number listed twice", card)
+            }
+            ParseErrorKind::DuplicateCard { card } => write!(f, "card {} listed twice", card),
+            ParseErrorKind::OutOfOrder { card, expected } => {
+                write!(f, "expected card {}, found card {}", expected, card)
+            }
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// Total score of all cards.
+pub fn part1(deck: &Deck) -> u64 {
+    deck.score()
+}
+
+/// Total number of cards after every card won copies of the cards below it.
+pub fn part2(deck: &Deck, overflow: Overflow) -> Result<u64, OverflowError> {
+    deck.total_cards(overflow)
+}
+
+/// A single scratchcard.
+#[derive(Debug, Clone)]
+pub struct Card {
+    pub id: u32,
+    winning_numbers: Vec<i32>,
+    numbers_you_have: Vec<i32>,
+    /// The winning numbers again, to look matches up in.
+    winning: HashSet<i32>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    /// Parses a `Card 1: 41 48 83 | 83 86  6` line.
+    fn from_str(card_str: &str) -> Result<Self, Self::Err> {
+        let (id, numbers) = Self::split_header(card_str)?;
+        let card = id
+            .parse()
+            .map_err(|_| ParseError::new(ParseErrorKind::InvalidId, card_str, id))?;
+
+        let (winning_numbers, numbers_you_have) = numbers.split_once('|').ok_or_else(|| {
+            let kind = ParseErrorKind::MissingSeparator { card };
+            ParseError::new(kind, card_str, &card_str[card_str.len()..])
+        })?;
+
+        Ok(Self::new(
+            card,
+            Self::get_numbers(card, card_str, winning_numbers)?,
+            Self::get_numbers(card, card_str, numbers_you_have)?,
+        ))
+    }
+}
+
+impl Card {
+    pub fn new(id: u32, winning_numbers: Vec<i32>, numbers_you_have: Vec<i32>) -> Self {
+        let winning = winning_numbers.iter().copied().collect();
+
+        Self {
+            id,
+            winning_numbers,
+            numbers_you_have,
+            winning,
+        }
+    }
+
+    pub fn winning_numbers(&self) -> &[i32] {
+        &self.winning_numbers
+    }
+
+    pub fn numbers_you_have(&self) -> &[i32] {
+        &self.numbers_you_have
+    }
+
+    /// How many of the numbers you have are winning numbers.
+    pub fn win_count(&self) -> usize {
+        self.numbers_you_have
+            .iter()
+            .filter(|n| self.winning.contains(n))
+            .count()
+    }
+
+    /// One point for the first match, doubled for every match after that,
+    /// saturating at `u64::MAX`.
+    pub fn score(&self) -> u64 {
+        match self.win_count() {
+            0 => 0,
+            win_count => 1u64.checked_shl(win_count as u32 - 1).unwrap_or(u64::MAX),
+        }
+    }
+
+    // The id and the numbers of a `Card 1: 41 48 | 83 86` line
+    fn split_header(card_str: &str) -> Result<(&str, &str), ParseError> {
+        card_str
+            .strip_prefix("Card")
+            .and_then(|rest| rest.split_once(':'))
+            .map(|(id, numbers)| (id.trim(), numbers))
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingHeader, card_str, card_str))
+    }
+
+    fn get_numbers(card: u32, card_str: &str, number_string: &str) -> Result<Vec<i32>, ParseError> {
+        let mut seen = HashSet::new();
+
+        number_string
+            .split_whitespace()
+            .map(|s| {
+                let number = s.parse::<i32>().map_err(|_| {
+                    ParseError::new(ParseErrorKind::InvalidNumber { card }, card_str, s)
+                })?;
+
+                match seen.insert(number) {
+                    true => Ok(number),
+                    false => Err(ParseError::new(
+                        ParseErrorKind::DuplicateNumber { card },
+                        card_str,
+                        s,
+                    )),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_from_str() {
+        let card_str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let card = Card::from_str(card_str).unwrap();
+
+        assert_eq!(card.id, 1);
+        assert_eq!(card.winning_numbers, vec![41, 48, 83, 86, 17]);
+        assert_eq!(card.numbers_you_have, vec![83, 86, 6, 31, 17, 9, 48, 53]);
+    }
+
+    #[test]
+    fn test_card_from_str_errors() {
+        let error = Card::from_str("Card 1 41 48 | 83 86").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingHeader);
+
+        let error = Card::from_str("Card 1: 41 48 83 86").unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::MissingSeparator { card: 1 }, 20));
+
+        let error = Card::from_str("Card 1: 41 4x | 83 86").unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidNumber { card: 1 }, 12, "4x"));
+
+        let error = Card::from_str("Card x: 41 | 83").unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidId, 6));
+
+        let error = Card::from_str("Card  12: 41 48 | 83 86 83").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 25: card 12: number listed twice \"83\"");
+        assert_eq!((error.kind, error.column), (ParseErrorKind::DuplicateNumber { card: 12 }, 25));
+    }
+
+    #[test]
+    fn test_parse_document_ids() {
+        let cards = parse_document("Card 3: 1 | 1\nCard 4: 2 | 1").unwrap();
+        assert_eq!(cards.iter().map(|card| card.id).collect::<Vec<_>>(), [3, 4]);
+
+        let error = parse_document("Card 1: 1 | 1\nCard 2: 1 | 1\nCard 1: 1 | 1").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::DuplicateCard { card: 1 }, 3, 6));
+
+        let error = parse_document("Card 1: 1 | 1\nCard 3: 1 | 1").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 6: expected card 2, found card 3 \"3\"");
+        assert_eq!(error.kind, ParseErrorKind::OutOfOrder { card: 3, expected: 2 });
+    }
+
+    #[test]
+    fn test_card_score() {
+        let card = Card::new(1, vec![1, 2, 3, 4], vec![1, 2, 3, 5]);
+
+        assert_eq!(card.score(), 4);
+    }
+}
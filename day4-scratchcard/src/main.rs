@@ -1,111 +1,108 @@
-fn main() {
-    // Open file passed in ARGV
-    let args: Vec<String> = std::env::args().collect();
-    // Print usage if no file is passed
-    if args.len() < 2 {
-        println!("Usage: {} <filename>", args[0]);
-        return;
-    }
-
-    let filename = &args[1];
-    let document =
-        std::fs::read_to_string(filename).expect("Something went wrong reading the file");
-
-    let mut cards: Vec<Card> = document
-        .lines()
-        .map(Card::from_str)
-        .collect();
-
-    for i in 0..cards.len() {
-        let card = &cards[i];
-        let win_count = card.win_count() as usize;
-        let copies = card.copies;
-
-        if win_count > 0 {
-            for u in 1..=win_count {
-                let c = &mut cards[i + u];
-                c.copies += copies;
-            }
-        }
-    }
-
-    let sum: usize = cards.iter().map(|c| c.copies ).sum();
-
-    println!("Sum: {}", sum);
+use clap::{Parser, Subcommand};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use scratchcard::simulate::{simulate, DeckShape};
+use scratchcard::{Day4, Overflow};
+
+#[derive(Parser)]
+#[command(subcommand_negates_reqs = true)]
+struct Args {
+    #[command(flatten)]
+    options: aoc_core::cli::Options,
+
+    /// What happens to copies won of cards past the end of the deck
+    #[arg(long, value_enum, default_value_t = Overflow::Clamp)]
+    overflow: Overflow,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-struct Card {
-    winning_numbers: Vec<i32>,
-    numbers_you_have: Vec<i32>,
-    copies: usize,
+#[derive(Subcommand)]
+enum Command {
+    /// Play random decks and show how many cards they end with
+    Simulate {
+        /// Cards in every deck
+        #[arg(long, default_value_t = DeckShape::default().cards)]
+        cards: usize,
+
+        /// Winning numbers on every card
+        #[arg(long, default_value_t = DeckShape::default().winning)]
+        winning: usize,
+
+        /// Numbers you have on every card
+        #[arg(long, default_value_t = DeckShape::default().have)]
+        have: usize,
+
+        /// Smallest number on the cards
+        #[arg(long, default_value_t = *DeckShape::default().numbers.start())]
+        min: i32,
+
+        /// Largest number on the cards
+        #[arg(long, default_value_t = *DeckShape::default().numbers.end())]
+        max: i32,
+
+        /// Decks to play
+        #[arg(long, default_value_t = 1000)]
+        runs: usize,
+
+        /// Seed for the same decks every time
+        #[arg(long)]
+        seed: Option<u64>,
+    },
 }
 
-impl Card {
-    pub fn from_str(card_str: &str) -> Self {
-        let numbers: Vec<Vec<i32>> = card_str
-            .split(":")
-            .last()
-            .unwrap()
-            .split("|")
-            .map(Self::get_numbers)
-            .collect();
-
-        let winning_numbers = numbers[0].clone();
-        let numbers_you_have = numbers[1].clone();
-
-        Self {
-            winning_numbers,
-            numbers_you_have,
-            copies: 1,
+fn main() {
+    let args: Args = aoc_core::cli::parse(&Day4::default());
+
+    match args.command {
+        Some(Command::Simulate {
+            cards,
+            winning,
+            have,
+            min,
+            max,
+            runs,
+            seed,
+        }) => {
+            let shape = DeckShape {
+                cards,
+                winning,
+                have,
+                numbers: min..=max,
+            };
+            run_simulation(&shape, runs, seed);
         }
-    }
-
-    pub fn win_count(&self) -> usize {
-        self.winning_numbers
-        .iter()
-        .filter(|&n| self.numbers_you_have.contains(n))
-        .count() 
-    }
-
-    pub fn score(&self) -> i32 {
-        let win_count = self.win_count();
-
-        match win_count {
-            0 => 0,
-            _ => i32::pow(2, win_count as u32 - 1),
+        None => {
+            let overflow = args.overflow;
+            aoc_core::cli::run(&Day4 { overflow }, &args.options);
         }
     }
-
-    fn get_numbers(number_string: &str) -> Vec<i32> {
-        number_string
-            .split(" ")
-            .map(|s| s.parse::<i32>())
-            .filter_map(|x| x.ok())
-            .collect()
-    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_card_from_str() {
-        let card_str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
-        let card = Card::from_str(card_str);
-
-        assert_eq!(card.winning_numbers, vec![41, 48, 83, 86, 17]);
-        assert_eq!(card.numbers_you_have, vec![83, 86, 6, 31, 17, 9, 48, 53]);
+fn run_simulation(shape: &DeckShape, runs: usize, seed: Option<u64>) {
+    if let Err(message) = shape.validate() {
+        eprintln!("{}", message);
+        std::process::exit(1);
     }
 
-    #[test]
-    fn test_card_score() {
-        let card = Card {
-            winning_numbers: vec![1, 2, 3, 4],
-            numbers_you_have: vec![1, 2, 3, 5],
-            copies: 1,
-        };
-
-        assert_eq!(card.score(), 4);
-    }
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    println!(
+        "{} decks of {} cards with {} winning numbers and {} numbers you have from {}..={}",
+        runs,
+        shape.cards,
+        shape.winning,
+        shape.have,
+        shape.numbers.start(),
+        shape.numbers.end()
+    );
+    println!(
+        "{:.2} winning numbers per card on average\n",
+        shape.expected_wins()
+    );
+    println!("{}", simulate(shape, runs, &mut rng));
 }
@@ -0,0 +1,304 @@
+//! Random decks of scratchcards, to see how many cards the cascade ends with
+//! for decks shaped differently from the puzzle input.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use rand::seq::index;
+use rand::Rng;
+
+use crate::{Card, Deck, Overflow};
+
+/// The shape of random decks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckShape {
+    pub cards: usize,
+    /// Winning numbers on every card.
+    pub winning: usize,
+    /// Numbers you have on every card.
+    pub have: usize,
+    /// Numbers are drawn from this range, distinct on either side of a card.
+    pub numbers: RangeInclusive<i32>,
+}
+
+/// 200 cards with 5 winning numbers and 8 numbers you have, from 1 to 50.
+///
+/// These win 0.8 numbers per card on average, and decks end up with around a
+/// thousand cards. Random cards shaped like a puzzle input's, 10 and 25
+/// numbers from 1 to 99, win 2.5 on average: their copies multiply so fast
+/// that the totals saturate at `u64::MAX`.
+impl Default for DeckShape {
+    fn default() -> Self {
+        Self {
+            cards: 200,
+            winning: 5,
+            have: 8,
+            numbers: 1..=50,
+        }
+    }
+}
+
+impl DeckShape {
+    /// Checks there are enough numbers in the range for either side.
+    pub fn validate(&self) -> Result<(), String> {
+        let needed = self.winning.max(self.have);
+        if needed > self.available() {
+            return Err(format!(
+                "can't draw {} distinct numbers from {}..={}",
+                needed,
+                self.numbers.start(),
+                self.numbers.end()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// How many numbers a random card wins on average, 0 without any
+    /// numbers to draw from. Past 1, the number of copies grows exponentially
+    /// with the number of cards.
+    pub fn expected_wins(&self) -> f64 {
+        match self.available() {
+            0 => 0.0,
+            available => (self.winning * self.have) as f64 / available as f64,
+        }
+    }
+
+    /// A random deck of this shape, numbered from 1. The shape must be valid,
+    /// see [`DeckShape::validate`].
+    pub fn random_deck<R: Rng>(&self, rng: &mut R) -> Deck {
+        let cards = (1..)
+            .take(self.cards)
+            .map(|id| {
+                Card::new(
+                    id,
+                    self.random_numbers(rng, self.winning),
+                    self.random_numbers(rng, self.have),
+                )
+            })
+            .collect();
+
+        Deck::new(cards)
+    }
+
+    // How many numbers there are in the range
+    fn available(&self) -> usize {
+        let (start, end) = (*self.numbers.start(), *self.numbers.end());
+        usize::try_from(i64::from(end) - i64::from(start) + 1).unwrap_or(0)
+    }
+
+    fn random_numbers<R: Rng>(&self, rng: &mut R, amount: usize) -> Vec<i32> {
+        let start = i64::from(*self.numbers.start());
+
+        // Indices are below the size of the range, so every number fits
+        index::sample(rng, self.available(), amount)
+            .into_iter()
+            .map(|idx| (start + idx as i64) as i32)
+            .collect()
+    }
+}
+
+/// Total cards after the cascade over many random decks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    /// The total of every deck, sorted.
+    pub totals: Vec<u64>,
+}
+
+impl Distribution {
+    pub fn runs(&self) -> usize {
+        self.totals.len()
+    }
+
+    /// The mean total: the expected number of cards a deck ends with.
+    pub fn mean(&self) -> f64 {
+        self.totals.iter().map(|&total| total as f64).sum::<f64>() / self.runs() as f64
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.totals.first().copied()
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.totals.last().copied()
+    }
+
+    /// The total that a fraction `q` of the decks don't exceed.
+    pub fn quantile(&self, q: f64) -> Option<u64> {
+        let idx = (q.clamp(0.0, 1.0) * (self.runs() as f64 - 1.0)).round() as usize;
+        self.totals.get(idx).copied()
+    }
+
+    /// How many decks fall in each of up to `buckets` equally wide ranges
+    /// from the smallest to the largest total, as `(start, end, count)` with
+    /// `end` exclusive except for the last bucket.
+    pub fn histogram(&self, buckets: usize) -> Vec<(u64, u64, usize)> {
+        let (Some(min), Some(max)) = (self.min(), self.max()) else {
+            return Vec::new();
+        };
+
+        let width = (max - min)
+            .saturating_add(1)
+            .div_ceil(buckets.max(1) as u64);
+        let mut histogram: Vec<(u64, u64, usize)> = (0..buckets.max(1) as u64)
+            .map(|idx| {
+                let start = min.saturating_add(idx.saturating_mul(width));
+                (start, start.saturating_add(width), 0)
+            })
+            .take_while(|&(start, _, _)| start <= max)
+            .collect();
+        if let Some(last) = histogram.last_mut() {
+            last.1 = max;
+        }
+
+        let last = histogram.len() - 1;
+        for &total in &self.totals {
+            let idx = (((total - min) / width) as usize).min(last);
+            histogram[idx].2 += 1;
+        }
+
+        histogram
+    }
+}
+
+/// Plays `runs` random decks of `shape`, clamping copies won past the end of
+/// a deck. The shape must be valid, see [`DeckShape::validate`].
+pub fn simulate<R: Rng>(shape: &DeckShape, runs: usize, rng: &mut R) -> Distribution {
+    let mut totals: Vec<u64> = (0..runs)
+        .map(|_| {
+            let deck = shape.random_deck(rng);
+            deck.total_cards(Overflow::Clamp)
+                .expect("clamping never overflows")
+        })
+        .collect();
+    totals.sort_unstable();
+
+    Distribution { totals }
+}
+
+/// The mean, quantiles, and a histogram with a bar per bucket.
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (Some(min), Some(max)) = (self.min(), self.max()) else {
+            return write!(f, "no decks");
+        };
+
+        writeln!(f, "mean   {:.1}", self.mean())?;
+        writeln!(f, "min    {}", min)?;
+        for (name, q) in [("p10", 0.1), ("median", 0.5), ("p90", 0.9)] {
+            writeln!(f, "{:<6} {}", name, self.quantile(q).unwrap_or(0))?;
+        }
+        writeln!(f, "max    {}", max)?;
+
+        let histogram = self.histogram(10);
+        let most = histogram
+            .iter()
+            .map(|&(_, _, count)| count)
+            .max()
+            .unwrap_or(1);
+        for (start, end, count) in histogram {
+            let bar = "#".repeat((count * 40).div_ceil(most));
+            write!(f, "\n{:>10}..{:<10} {:>6}", start, end, count)?;
+            if !bar.is_empty() {
+                write!(f, " {}", bar)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_random_deck() {
+        let shape = DeckShape {
+            cards: 5,
+            winning: 3,
+            have: 4,
+            numbers: 10..=14,
+        };
+        let deck = shape.random_deck(&mut StdRng::seed_from_u64(1));
+
+        assert_eq!(deck.cards().len(), 5);
+        for card in deck.cards() {
+            assert_eq!(card.winning_numbers().len(), 3);
+            assert!(card
+                .numbers_you_have()
+                .iter()
+                .all(|n| (10..=14).contains(n)));
+            // 4 of 5 numbers always hit at least 2 of 3 winning ones
+            assert!(card.win_count() >= 2);
+        }
+        assert_eq!(deck.cards()[4].id, 5);
+    }
+
+    #[test]
+    fn test_full_range() {
+        let shape = DeckShape {
+            cards: 3,
+            numbers: i32::MIN..=i32::MAX,
+            ..DeckShape::default()
+        };
+        assert!(shape.validate().is_ok());
+
+        let deck = shape.random_deck(&mut StdRng::seed_from_u64(3));
+        assert_eq!(deck.cards()[2].numbers_you_have().len(), 8);
+    }
+
+    #[test]
+    fn test_validate() {
+        let shape = DeckShape {
+            numbers: 1..=7,
+            ..DeckShape::default()
+        };
+        assert_eq!(
+            shape.validate(),
+            Err("can't draw 8 distinct numbers from 1..=7".to_string())
+        );
+        assert!(DeckShape::default().validate().is_ok());
+        assert_eq!(DeckShape::default().expected_wins(), 0.8);
+
+        let shape = DeckShape {
+            winning: 0,
+            have: 0,
+            numbers: RangeInclusive::new(1, 0),
+            ..DeckShape::default()
+        };
+        assert!(shape.validate().is_ok());
+        assert_eq!(shape.expected_wins(), 0.0);
+    }
+
+    #[test]
+    fn test_simulate() {
+        // Every number you have wins: every card copies all the ones after it
+        let shape = DeckShape {
+            cards: 4,
+            winning: 3,
+            have: 3,
+            numbers: 1..=3,
+        };
+        let distribution = simulate(&shape, 10, &mut StdRng::seed_from_u64(2));
+        assert_eq!(distribution.totals, [15; 10]);
+        assert_eq!(distribution.mean(), 15.0);
+        assert_eq!(distribution.histogram(5), [(15, 15, 10)]);
+    }
+
+    #[test]
+    fn test_distribution() {
+        let distribution = Distribution {
+            totals: vec![1, 2, 3, 4, 10],
+        };
+        assert_eq!(distribution.mean(), 4.0);
+        assert_eq!(distribution.quantile(0.5), Some(3));
+        assert_eq!(
+            distribution.histogram(3),
+            [(1, 5, 4), (5, 9, 0), (9, 10, 1)]
+        );
+    }
+}
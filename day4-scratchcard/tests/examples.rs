@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use scratchcard::Day4;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day4::default().part1(EXAMPLE).unwrap(), Answer::Number(13));
+    assert_eq!(Day4::default().part2(EXAMPLE).unwrap(), Answer::Number(30));
+}
@@ -0,0 +1,338 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! The almanac maps seeds through soil, fertilizer, water, light,
+//! temperature and humidity to a location.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+
+pub mod range_map;
+use range_map::RangeMap;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn name(&self) -> &'static str {
+        "seeds"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_document(input)?)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&parse_document(input)?)?.into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(parse_document(input)?, part1, part2)))
+    }
+}
+
+/// Lowest location of any seed listed on the seeds line.
+pub fn part1(almanac: &Almanac) -> Result<u64, SeedError> {
+    almanac
+        .seeds
+        .individual_seeds()
+        .map(|seed| almanac.location(seed))
+        .min()
+        .ok_or(SeedError::NoSeeds)
+}
+
+/// Lowest location of any seed in the seed ranges.
+pub fn part2(almanac: &Almanac) -> Result<u64, SeedError> {
+    almanac
+        .lowest_location(&almanac.seeds.ranges()?)
+        .ok_or(SeedError::NoSeeds)
+}
+
+/// The maps a seed goes through to its location, in order.
+pub const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// The parsed almanac: the seeds to plant and the named maps
+/// (`seed-to-soil`, ...) between categories.
+///
+/// Parsed almanacs have every map in [`MAPS`]; following seeds through one
+/// that lacks any of them panics.
+#[derive(Debug)]
+pub struct Almanac<'a> {
+    pub seeds: Seeds,
+    pub maps: HashMap<&'a str, RangeMap>,
+}
+
+impl Almanac<'_> {
+    /// Follows a seed through all maps to its location.
+    pub fn location(&self, seed: u64) -> u64 {
+        self.chain().fold(seed, |number, map| map.get(number))
+    }
+
+    /// Follows whole ranges of seeds through all maps, to the sorted ranges
+    /// of locations they end up at.
+    pub fn locations(&self, seeds: &[Range<u64>]) -> Vec<Range<u64>> {
+        self.chain()
+            .fold(seeds.to_vec(), |ranges, map| map.get_ranges(&ranges))
+    }
+
+    /// All maps fused into one, from seeds straight to locations.
+    pub fn seed_to_location(&self) -> RangeMap {
+        self.chain()
+            .fold(RangeMap::default(), |fused, map| fused.compose(map))
+    }
+
+    /// Lowest location of any seed in `seeds`, if there are any.
+    pub fn lowest_location(&self, seeds: &[Range<u64>]) -> Option<u64> {
+        self.locations(seeds).first().map(|range| range.start)
+    }
+
+    // The maps in the order of MAPS
+    fn chain(&self) -> impl Iterator<Item = &RangeMap> {
+        MAPS.iter().map(|name| {
+            self.maps
+                .get(name)
+                .unwrap_or_else(|| panic!("the almanac has no {} map", name))
+        })
+    }
+}
+
+/// The numbers on the seeds line, which the two parts read differently.
+#[derive(Debug)]
+pub struct Seeds {
+    values: Vec<u64>,
+}
+
+impl Seeds {
+    /// Part 1 reads the seed line as a plain list of seeds.
+    pub fn individual_seeds(&self) -> impl Iterator<Item = u64> + '_ {
+        self.values.iter().copied()
+    }
+
+    /// Every range of seeds, as part 2 reads the seed line: `(start, count)`
+    /// pairs, leaving out those with a count of 0.
+    pub fn ranges(&self) -> Result<Vec<Range<u64>>, SeedError> {
+        if !self.values.len().is_multiple_of(2) {
+            return Err(SeedError::MissingLength);
+        }
+
+        Ok(self
+            .values
+            .chunks(2)
+            .filter(|pair| pair[1] > 0)
+            .map(|pair| pair[0]..pair[0].saturating_add(pair[1]))
+            .collect())
+    }
+}
+
+/// Seeds that can't be planted as one of the parts reads them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeedError {
+    NoSeeds,
+    MissingLength,
+}
+
+impl fmt::Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeedError::NoSeeds => write!(f, "no seeds to plant"),
+            SeedError::MissingLength => write!(f, "the last seed range has no length"),
+        }
+    }
+}
+
+impl Error for SeedError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingSeeds,
+    NoSeeds,
+    MissingMap(&'static str),
+    InvalidNumber,
+    InvalidRange,
+    OverlappingRanges,
+    OutOfBounds,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingSeeds => write!(f, "expected a \"seeds:\" line"),
+            ParseErrorKind::NoSeeds => write!(f, "expected at least one seed"),
+            ParseErrorKind::MissingMap(name) => write!(f, "expected a \"{} map:\" block", name),
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::InvalidRange => write!(f, "expected \"<destination> <source> <length>\""),
+            ParseErrorKind::OverlappingRanges => write!(f, "source range overlaps another one"),
+            ParseErrorKind::OutOfBounds => write!(f, "range goes past the largest number"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// Parses the seeds line and every `name map:` block, which must include
+/// every map in [`MAPS`].
+pub fn parse_document(document: &str) -> Result<Almanac<'_>, ParseError> {
+    let mut seeds = None;
+    let mut maps: HashMap<&str, RangeMap> = HashMap::new();
+
+    let mut map_buffer = String::new();
+    let mut map_name = "";
+    // Line the buffered map starts on, to point errors at the right line
+    let mut map_start = 0;
+
+    for (idx, line) in document.lines().enumerate() {
+        if let Some(seed_str) = line.strip_prefix("seeds:") {
+            seeds = Some(generate_seeds(line, seed_str.trim_start()).map_err(|e| e.below(idx))?);
+        } else if line.contains("map:") {
+            map_name = line.split_whitespace().next().unwrap();
+            map_start = idx + 1;
+        } else if line.is_empty() {
+            if !map_buffer.is_empty() {
+                let map = RangeMap::from_str(map_buffer.as_str()).map_err(|e| e.below(map_start))?;
+                maps.insert(map_name, map);
+                map_buffer = String::new();
+            }
+        } else {
+            map_buffer.push_str(line);
+            map_buffer.push('\n');
+        }
+    }
+
+    if !map_buffer.is_empty() {
+        let map = RangeMap::from_str(map_buffer.as_str()).map_err(|e| e.below(map_start))?;
+        maps.insert(map_name, map);
+    }
+
+    let seeds = seeds.ok_or_else(|| ParseError::new(ParseErrorKind::MissingSeeds, document, ""))?;
+
+    // Missing maps would have come after the last line
+    if let Some(name) = MAPS.into_iter().find(|name| !maps.contains_key(name)) {
+        let error = ParseError::new(ParseErrorKind::MissingMap(name), "", "");
+        return Err(error.below(document.lines().count()));
+    }
+
+    Ok(Almanac { seeds, maps })
+}
+
+fn generate_seeds(line: &str, seed_str: &str) -> Result<Seeds, ParseError> {
+    let values = seed_str
+        .split_whitespace()
+        .map(|seed| {
+            seed.parse::<u64>()
+                .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, line, seed))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if values.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::NoSeeds, line, &line[line.len()..]));
+    }
+
+    Ok(Seeds { values })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_document() {
+        let almanac = parse_document(include_str!("../example.txt")).unwrap();
+
+        assert_eq!(almanac.maps.len(), 7);
+        assert_eq!(almanac.maps.get("seed-to-soil").unwrap().get(14), 14);
+        assert_eq!(almanac.seeds.individual_seeds().collect::<Vec<_>>(), [79, 14, 55, 13]);
+    }
+
+    #[test]
+    fn test_locations() {
+        let almanac = parse_document(include_str!("../example.txt")).unwrap();
+
+        assert_eq!(almanac.location(79), 82);
+        assert_eq!(almanac.locations(&[79..80, 14..15]), [43..44, 82..83]);
+        assert_eq!(almanac.lowest_location(&[82..83, 14..15]), Some(43));
+        assert_eq!(almanac.lowest_location(&[]), None);
+
+        // Nothing maps the largest number, which is its own location
+        let example = include_str!("../example.txt");
+        let input = example.replace("79 14 55 13", "18446744073709551615 18446744073709551615");
+        assert_eq!(part1(&parse_document(&input).unwrap()), Ok(u64::MAX));
+
+        let seeds = almanac.seeds.ranges().unwrap();
+        let lowest = seeds
+            .iter()
+            .flat_map(|range| range.clone().map(|seed| almanac.location(seed)))
+            .min();
+        assert_eq!(almanac.lowest_location(&seeds), lowest);
+
+        let fused = almanac.seed_to_location();
+        for seed in 0..120 {
+            assert_eq!(fused.get(seed), almanac.location(seed));
+        }
+        assert_eq!(fused.get_ranges(&seeds), almanac.locations(&seeds));
+    }
+
+    #[test]
+    fn test_parse_document_errors() {
+        let error = parse_document("test-one map:\n50 98 2").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingSeeds);
+
+        let input = "seeds: 79 14\n\ntest-one map:\n50 98 2\n52 50 x\n";
+        let error = parse_document(input).unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidNumber, 5, 7));
+
+        let error = parse_document("seeds:\n\nseed-to-soil map:\n50 98 2").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::NoSeeds, 1, 7));
+
+        let example = include_str!("../example.txt");
+        let input = example.replace("light-to-temperature", "light-to-heat");
+        let error = parse_document(&input).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingMap("light-to-temperature"));
+        assert_eq!(error.line, example.lines().count() + 1);
+        assert_eq!(
+            error.to_string(),
+            format!("line {}, column 1: expected a \"light-to-temperature map:\" block", error.line)
+        );
+    }
+
+    #[test]
+    fn test_seed_readings() {
+        let example = include_str!("../example.txt");
+
+        // An odd number of seeds is only a problem when reading ranges
+        let input = example.replace("79 14 55 13", "79 14 55");
+        let almanac = parse_document(&input).unwrap();
+        assert_eq!(almanac.seeds.individual_seeds().collect::<Vec<_>>(), [79, 14, 55]);
+        assert_eq!(part1(&almanac), Ok(43));
+        assert_eq!(part2(&almanac), Err(SeedError::MissingLength));
+
+        // Nor is a range of no seeds, which part 2 leaves out
+        let input = example.replace("79 14 55 13", "79 14 55 0 90 5");
+        let almanac = parse_document(&input).unwrap();
+        assert_eq!(almanac.seeds.ranges(), Ok(vec![79..93, 90..95]));
+        assert_eq!(part2(&almanac), Ok(46));
+
+        let input = example.replace("79 14 55 13", "79 0");
+        let almanac = parse_document(&input).unwrap();
+        assert_eq!(part1(&almanac), Ok(22));
+        assert_eq!(part2(&almanac), Err(SeedError::NoSeeds));
+    }
+}
@@ -1,50 +1,273 @@
+use std::error::Error;
+use std::fmt;
 use std::ops::Range;
-#[derive(Debug)]
+use std::str::FromStr;
+
+use crate::{ParseError, ParseErrorKind};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct PointedRange {
     source_range: Range<u64>,
-    destination_offset: i64,
+    destination_offset: i128,
+}
+
+impl PointedRange {
+    fn destination(&self) -> Range<u64> {
+        let offset = self.destination_offset;
+        shift(self.source_range.start, offset)..shift(self.source_range.end, offset)
+    }
 }
 
+/// Maps numbers from source ranges to destination ranges; numbers outside of
+/// any range map to themselves.
+///
+/// The source ranges are sorted and don't overlap, so every number maps to a
+/// single one. Maps cover the numbers below `u64::MAX`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct RangeMap {
     ranges: Vec<PointedRange>,
 }
 
+/// A map sending several numbers to the same one, which can't be undone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotInvertible {
+    /// Numbers more than one range maps to.
+    pub destination: Range<u64>,
+}
+
+impl fmt::Display for NotInvertible {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}..{} is mapped to more than once",
+            self.destination.start, self.destination.end
+        )
+    }
+}
+
+impl Error for NotInvertible {}
+
+impl FromStr for RangeMap {
+    type Err = ParseError;
+
+    /// Parses `destination source length` lines, whose source ranges may not
+    /// overlap.
+    fn from_str(map_str: &str) -> Result<Self, Self::Err> {
+        let ranges: Vec<PointedRange> = aoc_core::parse_lines(map_str, |line| {
+            let parts: Vec<u64> = line
+                .split_whitespace()
+                .map(|part| {
+                    part.parse::<u64>()
+                        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, line, part))
+                })
+                .collect::<Result<_, _>>()?;
+
+            let &[destination, source, length] = parts.as_slice() else {
+                return Err(ParseError::new(ParseErrorKind::InvalidRange, line, line));
+            };
+            if destination.checked_add(length).is_none() || source.checked_add(length).is_none() {
+                return Err(ParseError::new(ParseErrorKind::OutOfBounds, line, line));
+            }
+
+            Ok(PointedRange {
+                source_range: source..source + length,
+                destination_offset: i128::from(destination) - i128::from(source),
+            })
+        })?;
+
+        // Ranges by where they start, remembering the line they're on
+        let mut lines: Vec<usize> = (0..ranges.len())
+            .filter(|&idx| !ranges[idx].source_range.is_empty())
+            .collect();
+        lines.sort_by_key(|&idx| ranges[idx].source_range.start);
+
+        for pair in lines.windows(2) {
+            if ranges[pair[1]].source_range.start < ranges[pair[0]].source_range.end {
+                let idx = pair[0].max(pair[1]);
+                let line = map_str.lines().nth(idx).unwrap_or_default();
+                let error = ParseError::new(ParseErrorKind::OverlappingRanges, line, line);
+                return Err(error.below(idx));
+            }
+        }
+
+        let ranges = lines.into_iter().map(|idx| ranges[idx].clone()).collect();
+        Ok(Self { ranges })
+    }
+}
+
 impl RangeMap {
-    pub fn from_str(map_str: &str) -> Self {
-        let mut ranges: Vec<PointedRange> = map_str
-            .lines()
-            .map(|line| {
-                let parts: Vec<u64> = line
-                    .split_whitespace()
-                    .map(str::parse::<u64>)
-                    .flat_map(Result::ok)
-                    .collect();
-
-                let source_range: Range<u64> = Range {
-                    start: parts[1],
-                    end: parts[1] + parts[2],
-                };
-                let destination_offset: i64 = parts[0] as i64 - parts[1] as i64;
-
-                PointedRange {
-                    source_range,
-                    destination_offset,
-                }
+    /// Looks up where `source` maps to.
+    pub fn get(&self, source: u64) -> u64 {
+        let idx = self
+            .ranges
+            .partition_point(|range| range.source_range.end <= source);
+
+        match self.ranges.get(idx) {
+            Some(range) if range.source_range.contains(&source) => {
+                shift(source, range.destination_offset)
+            }
+            _ => source,
+        }
+    }
+
+    /// Every source range with the destination range it maps to, sorted by
+    /// source.
+    pub fn ranges(&self) -> impl Iterator<Item = (Range<u64>, Range<u64>)> + '_ {
+        self.ranges
+            .iter()
+            .map(|range| (range.source_range.clone(), range.destination()))
+    }
+
+    /// Where every number in `range` maps to, as the ranges it's split into
+    /// by the boundaries of the map, in the order of `range`.
+    pub fn get_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        self.split(range)
+            .into_iter()
+            .map(|(part, offset)| shift(part.start, offset)..shift(part.end, offset))
+            .collect()
+    }
+
+    /// Where every number in `ranges` maps to, as sorted ranges without any
+    /// overlap.
+    pub fn get_ranges(&self, ranges: &[Range<u64>]) -> Vec<Range<u64>> {
+        let output = ranges
+            .iter()
+            .flat_map(|range| self.get_range(range.clone()))
+            .collect();
+
+        merge(output)
+    }
+
+    /// The map sending every number where `other` sends what this map sends
+    /// it to: `self.compose(&other).get(n) == other.get(self.get(n))`.
+    pub fn compose(&self, other: &RangeMap) -> RangeMap {
+        let mut pieces = Vec::new();
+        for piece in self.pieces() {
+            let offset = piece.destination_offset;
+            for (part, other_offset) in other.split(piece.destination()) {
+                pieces.push(PointedRange {
+                    source_range: shift(part.start, -offset)..shift(part.end, -offset),
+                    destination_offset: offset + other_offset,
+                });
+            }
+        }
+
+        Self::from_pieces(pieces)
+    }
+
+    /// The map sending every number back to where it came from, unless two
+    /// numbers map to the same one.
+    pub fn inverse(&self) -> Result<RangeMap, NotInvertible> {
+        let mut pieces: Vec<PointedRange> = self
+            .pieces()
+            .into_iter()
+            .map(|piece| PointedRange {
+                source_range: piece.destination(),
+                destination_offset: -piece.destination_offset,
             })
             .collect();
+        pieces.sort_by_key(|piece| piece.source_range.start);
+
+        for pair in pieces.windows(2) {
+            let (first, second) = (&pair[0].source_range, &pair[1].source_range);
+            if second.start < first.end {
+                return Err(NotInvertible {
+                    destination: second.start..first.end.min(second.end),
+                });
+            }
+        }
+
+        Ok(Self::from_pieces(pieces))
+    }
+
+    // `range` split by the boundaries of the map, with the offset of every
+    // part
+    fn split(&self, range: Range<u64>) -> Vec<(Range<u64>, i128)> {
+        let mut parts = Vec::new();
+        let mut start = range.start;
+
+        // Skip the ranges that end before this one starts
+        let first = self
+            .ranges
+            .partition_point(|mapped| mapped.source_range.end <= start);
+        for mapped in &self.ranges[first..] {
+            let source = &mapped.source_range;
+            if start >= range.end || source.start >= range.end {
+                break;
+            }
+
+            if start < source.start {
+                parts.push((start..source.start, 0));
+                start = source.start;
+            }
+
+            let end = source.end.min(range.end);
+            parts.push((start..end, mapped.destination_offset));
+            start = end;
+        }
 
-        ranges.sort_by_key(|range| range.source_range.start);
+        if start < range.end {
+            parts.push((start..range.end, 0));
+        }
+
+        parts
+    }
+
+    // The ranges, with the numbers in between mapping to themselves, covering
+    // every number below u64::MAX
+    fn pieces(&self) -> Vec<PointedRange> {
+        self.split(0..u64::MAX)
+            .into_iter()
+            .map(|(source_range, destination_offset)| PointedRange {
+                source_range,
+                destination_offset,
+            })
+            .collect()
+    }
+
+    // A map of sorted pieces that don't overlap, leaving out the ones mapping
+    // to themselves and joining neighbours with the same offset
+    fn from_pieces(pieces: impl IntoIterator<Item = PointedRange>) -> Self {
+        let mut ranges: Vec<PointedRange> = Vec::new();
+        for piece in pieces {
+            if piece.destination_offset == 0 || piece.source_range.is_empty() {
+                continue;
+            }
+
+            match ranges.last_mut() {
+                Some(last)
+                    if last.source_range.end == piece.source_range.start
+                        && last.destination_offset == piece.destination_offset =>
+                {
+                    last.source_range.end = piece.source_range.end;
+                }
+                _ => ranges.push(piece),
+            }
+        }
 
         Self { ranges }
     }
+}
 
-    pub fn get(&self, source: u64) -> u64 {
-      let ranges = &self.ranges;
-        ranges.into_iter()
-            .find(|&range| range.source_range.contains(&source))
-            .and_then(|range| source.checked_add_signed(range.destination_offset))
-            .unwrap_or(source)
+fn shift(number: u64, offset: i128) -> u64 {
+    (i128::from(number) + offset).clamp(0, i128::from(u64::MAX)) as u64
+}
+
+// Sorts `ranges`, joining the ones that overlap or touch, and dropping empty
+// ones
+fn merge(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
     }
+
+    merged
 }
 
 #[cfg(test)]
@@ -54,7 +277,7 @@ mod tests {
     #[test]
     fn test_rangemap_from_str() {
         let input = "50 98 2\n52 50 48";
-        let map = RangeMap::from_str(input);
+        let map = RangeMap::from_str(input).unwrap();
 
         assert_eq!(map.ranges.len(), 2);
         assert_eq!(map.ranges[0].destination_offset, 2);
@@ -65,11 +288,97 @@ mod tests {
     #[test]
     fn test_rangemap_get() {
         let input = "50 98 2\n52 50 48";
-        let map = RangeMap::from_str(input);
+        let map = RangeMap::from_str(input).unwrap();
 
         assert_eq!(map.get(14), 14);
         assert_eq!(map.get(79), 81);
         assert_eq!(map.get(97), 99);
         assert_eq!(map.get(99), 51);
     }
+
+    #[test]
+    fn test_rangemap_get_range() {
+        let map = RangeMap::from_str("50 98 2\n52 50 48").unwrap();
+
+        assert_eq!(bounds(map.get_range(10..20)), [(10, 20)]);
+        assert_eq!(bounds(map.get_range(45..55)), [(45, 50), (52, 57)]);
+        assert_eq!(bounds(map.get_range(79..93)), [(81, 95)]);
+        assert_eq!(
+            bounds(map.get_range(90..110)),
+            [(92, 100), (50, 52), (100, 110)]
+        );
+        assert!(map.get_range(60..60).is_empty());
+    }
+
+    #[test]
+    fn test_rangemap_get_ranges() {
+        let map = RangeMap::from_str("50 98 2\n52 50 48").unwrap();
+
+        assert_eq!(
+            bounds(map.get_ranges(&[90..110, 0..10])),
+            [(0, 10), (50, 52), (92, 110)]
+        );
+        assert!(map.get_ranges(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_rangemap_compose() {
+        let seed_to_soil = RangeMap::from_str("50 98 2\n52 50 48").unwrap();
+        let soil_to_fertilizer = RangeMap::from_str("0 15 37\n37 52 2\n39 0 15").unwrap();
+        let seed_to_fertilizer = seed_to_soil.compose(&soil_to_fertilizer);
+
+        for seed in 0..120 {
+            assert_eq!(
+                seed_to_fertilizer.get(seed),
+                soil_to_fertilizer.get(seed_to_soil.get(seed))
+            );
+        }
+        assert_eq!(seed_to_soil.compose(&RangeMap::default()), seed_to_soil);
+    }
+
+    #[test]
+    fn test_rangemap_inverse() {
+        let map = RangeMap::from_str("50 98 2\n52 50 48").unwrap();
+        let inverse = map.inverse().unwrap();
+
+        for number in 0..120 {
+            assert_eq!(inverse.get(map.get(number)), number);
+        }
+        assert_eq!(inverse.compose(&map), RangeMap::default());
+
+        // 10..15 and 0..5 both end up at 0..5
+        let error = RangeMap::from_str("0 10 5").unwrap().inverse().unwrap_err();
+        assert_eq!(error.destination, 0..5);
+        assert_eq!(error.to_string(), "0..5 is mapped to more than once");
+    }
+
+    #[test]
+    fn test_rangemap_ranges() {
+        let map = RangeMap::from_str("52 50 48\n50 98 2").unwrap();
+        let ranges: Vec<_> = map.ranges().collect();
+
+        assert_eq!(ranges, [(50..98, 52..100), (98..100, 50..52)]);
+    }
+
+    fn bounds(ranges: Vec<Range<u64>>) -> Vec<(u64, u64)> {
+        ranges.into_iter().map(|range| (range.start, range.end)).collect()
+    }
+
+    #[test]
+    fn test_rangemap_from_str_errors() {
+        let error = RangeMap::from_str("50 98 2\n52 50").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::InvalidRange, 2));
+
+        let error = RangeMap::from_str("50 98 2\n52 -50 48").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidNumber, 2, 4));
+
+        let error = RangeMap::from_str("50 98 2\n0 0 10\n52 97 5").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::OverlappingRanges, 3));
+
+        let error = RangeMap::from_str("0 18446744073709551615 2").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::OutOfBounds);
+
+        // Empty ranges overlap nothing
+        assert!(RangeMap::from_str("0 10 0\n50 5 10").is_ok());
+    }
 }
@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use seeds::Day5;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day5.part1(EXAMPLE).unwrap(), Answer::Number(35));
+    assert_eq!(Day5.part2(EXAMPLE).unwrap(), Answer::Number(46));
+}
@@ -0,0 +1,153 @@
+//! Day 6: Wait For It
+//!
+//! Toy boat races: holding the button longer makes the boat faster, but
+//! leaves less time to travel.
+
+use std::fmt;
+
+use aoc_core::{Solution, SolveResult};
+
+pub mod race;
+
+pub use crate::race::Race;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn name(&self) -> &'static str {
+        "race"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_document(input)?).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&parse_kerned_document(input)?).into())
+    }
+}
+
+/// Product of the number of ways to win every race.
+pub fn part1(races: &[Race]) -> usize {
+    races
+        .iter()
+        .map(|race| race.winnable().len())
+        .product()
+}
+
+/// Number of ways to win the single, kerned race.
+pub fn part2(race: &Race) -> i64 {
+    race.winnable_binary()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingLine(&'static str),
+    InvalidNumber,
+    MismatchedRaces,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingLine(label) => write!(f, "expected a \"{}:\" line", label),
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::MismatchedRaces => write!(f, "every race needs a time and a distance"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// Parses the `Time:` and `Distance:` lines into one [`Race`] per column.
+pub fn parse_document(document: &str) -> Result<Vec<Race>, ParseError> {
+    let (time_line, time_str) = labelled_line(document, 0, "Time")?;
+    let (distance_line, distance_str) = labelled_line(document, 1, "Distance")?;
+    let times = get_numbers(time_line, time_str)?;
+    let distances = get_numbers(distance_line, distance_str).map_err(|e| e.below(1))?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::new(ParseErrorKind::MismatchedRaces, distance_line, distance_str).below(1));
+    }
+
+    Ok(times
+        .iter()
+        .zip(distances.iter())
+        .map(|(a, b)| Race::new(*a, *b))
+        .collect())
+}
+
+/// Parses the document as a single race: the spaces between the numbers are
+/// just bad kerning.
+pub fn parse_kerned_document(document: &str) -> Result<Race, ParseError> {
+    let (time_line, time_str) = labelled_line(document, 0, "Time")?;
+    let (distance_line, distance_str) = labelled_line(document, 1, "Distance")?;
+    let time = get_kerned_number(time_line, time_str)?;
+    let distance = get_kerned_number(distance_line, distance_str).map_err(|e| e.below(1))?;
+
+    Ok(Race::new(time, distance))
+}
+
+// Line `idx` of the document and the text after its `label:` header
+fn labelled_line<'a>(document: &'a str, idx: usize, label: &'static str) -> Result<(&'a str, &'a str), ParseError> {
+    let missing = || ParseError::new(ParseErrorKind::MissingLine(label), document, "").below(idx);
+
+    let line = document.lines().nth(idx).ok_or_else(missing)?;
+    let numbers = line
+        .strip_prefix(label)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| ParseError::new(ParseErrorKind::MissingLine(label), line, line).below(idx))?;
+
+    Ok((line, numbers))
+}
+
+fn get_numbers(line: &str, numbers: &str) -> Result<Vec<i64>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|number| {
+            number
+                .parse::<i64>()
+                .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, line, number))
+        })
+        .collect()
+}
+
+fn get_kerned_number(line: &str, numbers: &str) -> Result<i64, ParseError> {
+    numbers
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, line, numbers.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kerned_document() {
+        let race = parse_kerned_document("Time:      7  15   30\nDistance:  9  40  200").unwrap();
+        assert_eq!(race.winnable_binary(), 71503);
+    }
+
+    #[test]
+    fn test_parse_document_errors() {
+        let error = parse_document("Time:      7  15   30").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::MissingLine("Distance"), 2));
+
+        let error = parse_document("Time:      7  15   30\nDistance:  9  4O  200").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidNumber, 2, 15));
+
+        let error = parse_document("Time:      7  15   30\nDistance:  9  40").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MismatchedRaces);
+    }
+}
@@ -1,3 +1,4 @@
+/// A boat race: how long it lasts and the record distance to beat.
 #[derive(Debug)]
 pub struct Race {
   duration: i64,
@@ -12,10 +13,17 @@ impl Race {
     }
   }
 
+  /// Number of hold-times that beat the record, found with a binary search
+  /// for the shortest winning hold-time.
   pub fn winnable_binary(&self) -> i64 {
     let mut lower_bound = 0;
     let mut upper_bound = self.duration / 2;
 
+    // Holding for half the race goes the farthest
+    if !self.wins_with(upper_bound) {
+      return 0;
+    }
+
     while upper_bound - lower_bound > 1 {
       let pos = (lower_bound + upper_bound) / 2;
       if self.wins_with(pos) {
@@ -25,20 +33,15 @@ impl Race {
       }
     }
     
-    return self.duration - (lower_bound * 2 + 1);
+    self.duration - (lower_bound * 2 + 1)
   }
 
-  // Returns all winnable hold-times
+  /// All hold-times that beat the record.
   pub fn winnable(&self) -> Vec<i64> {
-    (1..self.duration).filter_map(|hold_time| {
-      if self.wins_with(hold_time) {
-        Some(hold_time)
-      } else {
-        None
-      }
-    }).collect()
+    (1..self.duration).filter(|&hold_time| self.wins_with(hold_time)).collect()
   }
 
+  /// Whether holding the button for `hold_time` beats the record.
   pub fn wins_with(&self, hold_time: i64) -> bool {
     let distance_covered = (self.duration - hold_time) * hold_time;
     distance_covered > self.max_distance
@@ -71,4 +74,11 @@ mod tests {
     let race2 = Race::new(94, 1549);
     assert_eq!(race2.winnable_binary(), race2.winnable().len() as i64);
   }
+
+  #[test]
+  fn test_winnable_binary_unbeatable() {
+    assert_eq!(Race::new(7, 12).winnable_binary(), 0);
+    assert_eq!(Race::new(1, 0).winnable_binary(), 0);
+    assert_eq!(Race::new(2, 0).winnable_binary(), 1);
+  }
 }
\ No newline at end of file
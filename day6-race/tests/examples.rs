@@ -0,0 +1,17 @@
+use aoc_core::{Answer, Solution};
+use race::Day6;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+const INPUT_2: &str = include_str!("../input_2.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day6.part1(EXAMPLE).unwrap(), Answer::Number(288));
+    assert_eq!(Day6.part2(EXAMPLE).unwrap(), Answer::Number(71503));
+}
+
+#[test]
+fn test_input2() {
+    assert_eq!(Day6.part1(INPUT_2).unwrap(), Answer::Number(23501589));
+    assert_eq!(Day6.part2(INPUT_2).unwrap(), Answer::Number(23501589));
+}
@@ -1,12 +1,19 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 
+use crate::{ParseError, ParseErrorKind};
+
+/// A hand of five cards. Hands order by their rank first, and card by card
+/// after that.
 #[derive(Eq, PartialEq, Debug)]
 pub struct Hand {
     cards: Vec<char>,
+    jokers: bool,
 }
 
+/// The type of a hand, from weakest to strongest.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum Rank {
+pub enum Rank {
     HighCard,
     OnePair,
     TwoPair,
@@ -17,18 +24,48 @@ enum Rank {
 }
 
 const CARD_ORDER: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+// With jokers, J is the weakest card
+const JOKER_CARD_ORDER: [char; 13] = [
     'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
 ];
 
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, false)
+    }
+}
+
 impl Hand {
-    pub fn from_str(s: &str) -> Self {
+    /// Parses a hand like `32T3K`. With `jokers`, `J` cards are wildcards
+    /// that count as the weakest card.
+    pub fn parse(s: &str, jokers: bool) -> Result<Self, ParseError> {
+        if let Some((idx, _)) = s.char_indices().find(|(_, c)| !CARD_ORDER.contains(c)) {
+            return Err(ParseError::new(ParseErrorKind::InvalidCard, s, &s[idx..idx + 1]));
+        }
+        if s.len() != 5 {
+            return Err(ParseError::new(ParseErrorKind::InvalidHandSize, s, s));
+        }
+
         let cards = s.chars().collect();
 
-        Hand { cards }
+        Ok(Hand { cards, jokers })
     }
 
-    // Returns the rank of the hand and the highest card
-    fn rank(&self) -> Rank {
+    fn card_order(&self) -> &'static [char; 13] {
+        if self.jokers {
+            &JOKER_CARD_ORDER
+        } else {
+            &CARD_ORDER
+        }
+    }
+
+    /// The rank of the hand, with jokers resolved to the best possible rank.
+    pub fn rank(&self) -> Rank {
         let mut card_count =
             self.cards
                 .iter()
@@ -38,10 +75,16 @@ impl Hand {
                 });
 
         // Resolve jokers
-        let joker_count = card_count.remove(&'J').unwrap_or_default();
+        let joker_count = if self.jokers {
+            card_count.remove(&'J').unwrap_or_default()
+        } else {
+            0
+        };
         let mut counts: Vec<u8> = card_count.values().copied().collect();
         counts.sort_unstable_by(|a, b| b.cmp(a));
-        counts.get_mut(0).and_then(|c| Some(*c += joker_count));
+        if let Some(c) = counts.get_mut(0) {
+            *c += joker_count;
+        }
 
         match counts.as_slice() {
             [5] => Rank::FiveOfAKind,
@@ -60,11 +103,12 @@ impl Ord for Hand {
         let rank_cmp = self.rank().cmp(&other.rank());
 
         if rank_cmp.is_eq() {
+            let card_order = self.card_order();
             for (idx, &card) in self.cards.iter().enumerate() {
                 let other_card = other.cards[idx];
 
-                let card_idx = CARD_ORDER.iter().position(|&c| c == card).unwrap();
-                let other_card_idx = CARD_ORDER.iter().position(|&c| c == other_card).unwrap();
+                let card_idx = card_order.iter().position(|&c| c == card).unwrap();
+                let other_card_idx = card_order.iter().position(|&c| c == other_card).unwrap();
 
                 let card_cmp = card_idx.cmp(&other_card_idx);
                 if !card_cmp.is_eq() {
@@ -72,7 +116,7 @@ impl Ord for Hand {
                 }
             }
 
-            return std::cmp::Ordering::Equal;
+            std::cmp::Ordering::Equal
         } else {
             rank_cmp
         }
@@ -91,36 +135,41 @@ mod tests {
 
     #[test]
     fn test_hand_from_str() {
-        let hand = Hand::from_str("32T3K");
+        let hand = Hand::parse("32T3K", true).unwrap();
         assert_eq!(hand.cards, vec!('3', '2', 'T', '3', 'K'));
     }
 
     #[test]
     fn test_hand_rank() {
-        assert_eq!(Hand::from_str("AAAAA").rank(), Rank::FiveOfAKind);
-        assert_eq!(Hand::from_str("KAAAA").rank(), Rank::FourOfAKind);
-        assert_eq!(Hand::from_str("KKAAA").rank(), Rank::FullHouse);
-        assert_eq!(Hand::from_str("KKA23").rank(), Rank::OnePair);
-        assert_eq!(Hand::from_str("KK223").rank(), Rank::TwoPair);
-        assert_eq!(Hand::from_str("KKK23").rank(), Rank::ThreeOfAKind);
-        assert_eq!(Hand::from_str("KQ2T9").rank(), Rank::HighCard);
+        assert_eq!(Hand::parse("AAAAA", true).unwrap().rank(), Rank::FiveOfAKind);
+        assert_eq!(Hand::parse("KAAAA", true).unwrap().rank(), Rank::FourOfAKind);
+        assert_eq!(Hand::parse("KKAAA", true).unwrap().rank(), Rank::FullHouse);
+        assert_eq!(Hand::parse("KKA23", true).unwrap().rank(), Rank::OnePair);
+        assert_eq!(Hand::parse("KK223", true).unwrap().rank(), Rank::TwoPair);
+        assert_eq!(Hand::parse("KKK23", true).unwrap().rank(), Rank::ThreeOfAKind);
+        assert_eq!(Hand::parse("KQ2T9", true).unwrap().rank(), Rank::HighCard);
     }
 
     #[test]
     fn test_hand_rank_with_joker() {
-        assert_eq!(Hand::from_str("AAJJJ").rank(), Rank::FiveOfAKind);
+        assert_eq!(Hand::parse("AAJJJ", true).unwrap().rank(), Rank::FiveOfAKind);
+    }
+
+    #[test]
+    fn test_hand_rank_without_joker() {
+        assert_eq!(Hand::parse("AAJJJ", false).unwrap().rank(), Rank::FullHouse);
+        assert!(Hand::parse("T55J5", false).unwrap() > Hand::parse("KTJJT", false).unwrap());
+        assert!(Hand::parse("T55J5", true).unwrap() < Hand::parse("KTJJT", true).unwrap());
     }
 
     #[test]
     fn test_sorting_hands() {
-        let mut hands = vec![
-            Hand::from_str("QQQQQ"),
-            Hand::from_str("KKKKK"),
-            Hand::from_str("2AAAA"),
-            Hand::from_str("32222"),
-            Hand::from_str("23456"),
-            Hand::from_str("23457"),
-        ];
+        let mut hands = [Hand::parse("QQQQQ", true).unwrap(),
+            Hand::parse("KKKKK", true).unwrap(),
+            Hand::parse("2AAAA", true).unwrap(),
+            Hand::parse("32222", true).unwrap(),
+            Hand::parse("23456", true).unwrap(),
+            Hand::parse("23457", true).unwrap()];
 
         hands.sort();
 
@@ -129,4 +178,13 @@ mod tests {
         assert_eq!(hands[2].cards, vec!('2', 'A', 'A', 'A', 'A'));
         assert_eq!(hands[3].cards, vec!('3', '2', '2', '2', '2'));
     }
+
+    #[test]
+    fn test_hand_parse_errors() {
+        let error = Hand::parse("32X3K", false).unwrap_err();
+        assert_eq!((error.kind, error.column, error.text.as_str()), (ParseErrorKind::InvalidCard, 3, "X"));
+
+        let error = Hand::parse("32T3", false).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidHandSize);
+    }
 }
@@ -0,0 +1,117 @@
+//! Day 7: Camel Cards
+//!
+//! Poker-like hands are ranked from weakest to strongest and win their bid
+//! times their rank.
+
+use std::fmt;
+
+use aoc_core::{Solution, SolveResult};
+
+pub mod hand;
+
+pub use hand::{Hand, Rank};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn name(&self) -> &'static str {
+        "cards"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(input)?.into())
+    }
+}
+
+/// Total winnings, with `J` as a regular Jack.
+pub fn part1(document: &str) -> Result<u64, ParseError> {
+    Ok(total_winnings(parse_document(document, false)?))
+}
+
+/// Total winnings, with `J` as a Joker.
+pub fn part2(document: &str) -> Result<u64, ParseError> {
+    Ok(total_winnings(parse_document(document, true)?))
+}
+
+/// Sum of every bid multiplied by the rank of its hand among all hands.
+pub fn total_winnings(mut cards: Vec<(Hand, u32)>) -> u64 {
+    cards.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    cards
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (idx, (_, value))| acc + (idx + 1) as u64 * u64::from(*value))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidCard,
+    InvalidHandSize,
+    MissingBid,
+    InvalidBid,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidCard => write!(f, "not a card"),
+            ParseErrorKind::InvalidHandSize => write!(f, "a hand has five cards"),
+            ParseErrorKind::MissingBid => write!(f, "expected \"<hand> <bid>\""),
+            ParseErrorKind::InvalidBid => write!(f, "invalid bid"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// Parses every `hand bid` line.
+pub fn parse_document(document: &str, jokers: bool) -> Result<Vec<(Hand, u32)>, ParseError> {
+    aoc_core::parse_lines(document, |line| {
+        let (hand, bid) = line
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingBid, line, line))?;
+        let hand = Hand::parse(hand, jokers).map_err(|e| e.within(line, hand))?;
+        let bid = bid
+            .trim()
+            .parse()
+            .map_err(|_| ParseError::new(ParseErrorKind::InvalidBid, line, bid.trim()))?;
+
+        Ok((hand, bid))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_document_errors() {
+        let error = parse_document("32T3K 765\nT55J5", false).unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::MissingBid, 2));
+
+        let error = parse_document("32T3K 765\nT55Z5 684", false).unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidCard, 2, 4));
+
+        let error = parse_document("32T3K 76x", false).unwrap_err();
+        assert_eq!((error.kind, error.column), (ParseErrorKind::InvalidBid, 7));
+    }
+
+    #[test]
+    fn test_total_winnings_past_u32() {
+        let document = "32T3K 4294967295\nKK677 4294967295";
+        assert_eq!(part1(document), Ok(3 * 4294967295));
+    }
+}
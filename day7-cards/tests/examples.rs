@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use cards::Day7;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day7.part1(EXAMPLE).unwrap(), Answer::Number(6440));
+    assert_eq!(Day7.part2(EXAMPLE).unwrap(), Answer::Number(5905));
+}
@@ -0,0 +1,394 @@
+//! Day 8: Haunted Wasteland
+//!
+//! A network of nodes, each with a left and a right turn, to be traversed
+//! following a repeating list of directions.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+
+/// A single step in the list of directions.
+#[derive(Debug, PartialEq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// Every node with the nodes its left and right turns lead to.
+pub type Map = HashMap<Node, Turns>;
+/// A 3-letter address packed into an integer, see [`get_node`].
+pub type Node = u32;
+pub type Turns = (Node, Node);
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn name(&self) -> &'static str {
+        "maps"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_document(input)?)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&parse_document(input)?)?.into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(parse_document(input)?, part1, part2)))
+    }
+}
+
+/// Steps needed to walk from `AAA` to `ZZZ`.
+pub fn part1(network: &Network) -> Result<u32, TraverseError> {
+    traverse_map_human(&network.map, &network.directions)
+}
+
+/// Steps needed until all ghosts, starting on every `..A` node, are on a
+/// `..Z` node at the same time.
+pub fn part2(network: &Network) -> Result<u64, TraverseError> {
+    traverse_map_ghost(&network.map, &network.directions)
+}
+
+/// The directions to follow and the map of nodes.
+#[derive(Debug)]
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub map: Map,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    NoDirections,
+    InvalidDirection,
+    InvalidNodeLine,
+    InvalidAddress,
+    UnknownNode,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::NoDirections => write!(f, "expected a line of directions"),
+            ParseErrorKind::InvalidDirection => write!(f, "directions are either L or R"),
+            ParseErrorKind::InvalidNodeLine => write!(f, "expected \"AAA = (BBB, CCC)\""),
+            ParseErrorKind::InvalidAddress => write!(f, "addresses are 3 letters or digits"),
+            ParseErrorKind::UnknownNode => write!(f, "no node with this address"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// A walk through the map that can't get to its end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraverseError {
+    /// No node to start from, e.g. `AAA`, or `..A` for the ghosts.
+    NoStart(&'static str),
+    /// Following the directions from this node never gets to an end.
+    NoEnd(String),
+}
+
+impl fmt::Display for TraverseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraverseError::NoStart(address) => write!(f, "no {} node to start at", address),
+            TraverseError::NoEnd(address) => {
+                write!(f, "following the directions from {} never gets to an end", address)
+            }
+        }
+    }
+}
+
+impl Error for TraverseError {}
+
+/// Parses the directions line, followed by a blank line and the nodes.
+pub fn parse_document(document: &str) -> Result<Network, ParseError> {
+    let mut lines = document.lines();
+
+    let directions = parse_directions(lines.next().unwrap_or_default())?;
+    lines.next();
+    let map = parse_map(lines).map_err(|e| e.below(2))?;
+
+    Ok(Network { directions, map })
+}
+
+/// Follows the directions from `AAA` until reaching `ZZZ`.
+pub fn traverse_map_human(map: &Map, directions: &[Direction]) -> Result<u32, TraverseError> {
+    let start = get_node("AAA");
+    if !map.contains_key(&start) {
+        return Err(TraverseError::NoStart("AAA"));
+    }
+
+    let destination = get_node("ZZZ");
+    steps_to_end(map, directions, start, |node| node == destination)
+}
+
+/// Follows the directions from every `..A` node at once, until they're all
+/// on a `..Z` node. The count saturates at `u64::MAX`.
+pub fn traverse_map_ghost(map: &Map, directions: &[Direction]) -> Result<u64, TraverseError> {
+    let nodes: Vec<Node> = map.keys().cloned().filter(is_ghost_beginning).collect();
+    if nodes.is_empty() {
+        return Err(TraverseError::NoStart("..A"));
+    }
+
+    let end_node_steps = nodes
+        .into_iter()
+        .map(|node| steps_to_end(map, directions, node, |node| is_ghost_ending(&node)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(lcm(&end_node_steps))
+}
+
+// Steps from `start` to the first node that `is_end`. Past one step per node
+// and direction, the walk is going around in circles.
+fn steps_to_end<F>(
+    map: &Map,
+    directions: &[Direction],
+    start: Node,
+    is_end: F,
+) -> Result<u32, TraverseError>
+where
+    F: Fn(Node) -> bool,
+{
+    let limit = map.len().saturating_mul(directions.len());
+    let mut turns = directions.iter().cycle();
+    let mut node = start;
+    let mut turn_count = 0;
+
+    while !is_end(node) {
+        let (Some((left, right)), Some(direction)) = (map.get(&node), turns.next()) else {
+            return Err(TraverseError::NoEnd(get_address(start)));
+        };
+        if turn_count as usize >= limit {
+            return Err(TraverseError::NoEnd(get_address(start)));
+        }
+
+        node = match direction {
+            Direction::Left => *left,
+            Direction::Right => *right,
+        };
+
+        turn_count += 1;
+    }
+
+    Ok(turn_count)
+}
+
+fn lcm(numbers: &[u32]) -> u64 {
+    numbers.iter().fold(1, |lcm, &number| {
+        let number = u64::from(number);
+        (lcm / gcd(lcm, number)).saturating_mul(number)
+    })
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        return a;
+    }
+
+    gcd(b, a % b)
+}
+
+// All ghost beginnings end with 'A'
+fn is_ghost_beginning(node: &Node) -> bool {
+    *node & 0xff == 65
+}
+
+// All ghost endings end with 'Z'
+fn is_ghost_ending(node: &Node) -> bool {
+    *node & 0xff == 90
+}
+
+/// Parses a line of `L` and `R` directions.
+pub fn parse_directions(line: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut directions = Vec::new();
+
+    for (idx, c) in line.char_indices() {
+        let direction = match c {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => {
+                let text = &line[idx..idx + c.len_utf8()];
+                return Err(ParseError::new(ParseErrorKind::InvalidDirection, line, text));
+            }
+        };
+        directions.push(direction)
+    }
+
+    if directions.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::NoDirections, line, line));
+    }
+
+    Ok(directions)
+}
+
+/// Parses `AAA = (BBB, CCC)` lines, whose turns must lead to nodes on
+/// lines of their own.
+pub fn parse_map<'a, I>(lines: I) -> Result<Map, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut map = HashMap::new();
+    let mut lines_read = Vec::new();
+
+    for (idx, line) in lines.enumerate() {
+        let (source, left, right) = split_node_line(line).map_err(|e| e.below(idx))?;
+
+        map.insert(source, (left, right));
+        lines_read.push(line);
+    }
+
+    for (idx, line) in lines_read.into_iter().enumerate() {
+        // Lines are known to read "AAA = (BBB, CCC)" by now
+        for address in [&line[7..10], &line[12..15]] {
+            if !map.contains_key(&get_node(address)) {
+                let error = ParseError::new(ParseErrorKind::UnknownNode, line, address);
+                return Err(error.below(idx));
+            }
+        }
+    }
+
+    Ok(map)
+}
+
+fn split_node_line(line: &str) -> Result<(Node, Node, Node), ParseError> {
+    let invalid = || ParseError::new(ParseErrorKind::InvalidNodeLine, line, line);
+
+    let (source, turns) = line.split_once(" = ").ok_or_else(invalid)?;
+    let (left, right) = turns
+        .strip_prefix('(')
+        .and_then(|turns| turns.strip_suffix(')'))
+        .and_then(|turns| turns.split_once(", "))
+        .ok_or_else(invalid)?;
+
+    let address = |address: &str| {
+        if address.len() == 3 && address.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            Ok(get_node(address))
+        } else {
+            Err(ParseError::new(ParseErrorKind::InvalidAddress, line, address))
+        }
+    };
+
+    Ok((address(source)?, address(left)?, address(right)?))
+}
+
+/// The 3-letter address of a node, see [`get_node`].
+pub fn get_address(node: Node) -> String {
+    node.to_be_bytes()[1..].iter().map(|&b| b as char).collect()
+}
+
+/// Get a unique node for a 3-letter address.
+pub fn get_node(address: &str) -> Node {
+    let mut node: Node = 0;
+
+    for c in address.bytes() {
+        node <<= 8;
+        node += c as Node;
+    }
+
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_directions() {
+        let directions = parse_directions("LLRRLR").unwrap();
+        assert_eq!(
+            directions,
+            vec![
+                Direction::Left,
+                Direction::Left,
+                Direction::Right,
+                Direction::Right,
+                Direction::Left,
+                Direction::Right
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_map() {
+        let lines = vec!["AAA = (LLL, RRR)", "LLL = (LLL, LLL)", "RRR = (RRR, AAA)"];
+        let map = parse_map(lines.into_iter()).unwrap();
+
+        assert! {
+            map.contains_key(&0x414141)
+        }
+
+        assert_eq! {
+            map.get(&0x414141),
+            Some(&(0x4c4c4c, 0x525252))
+        }
+    }
+
+    #[test]
+    fn test_parse_document_errors() {
+        let error = parse_document("LRX\n\nAAA = (BBB, CCC)").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidDirection, 1, 3));
+
+        let error = parse_document("LR\n\nAAA = (BBB, CCC)\nBBB = BBB, CCC").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::InvalidNodeLine, 4));
+
+        let error = parse_document("LR\n\nAAA = (BB, CCC)").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column, error.text.as_str()), (ParseErrorKind::InvalidAddress, 3, 8, "BB"));
+
+        let error = parse_document("LR\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, QQQ)").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::UnknownNode, 4, 13));
+
+        let error = parse_document("\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::NoDirections);
+    }
+
+    #[test]
+    fn test_traverse_errors() {
+        // The example for part 2 has no AAA
+        let network = parse_document(
+            "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)",
+        )
+        .unwrap();
+        assert_eq!(part1(&network), Err(TraverseError::NoStart("AAA")));
+        assert_eq!(part2(&network), Ok(6));
+
+        let network = parse_document("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(part1(&network), Err(TraverseError::NoEnd("AAA".to_string())));
+        assert_eq!(
+            part1(&network).unwrap_err().to_string(),
+            "following the directions from AAA never gets to an end"
+        );
+
+        let network = parse_document("L\n\nBBB = (BBB, BBB)").unwrap();
+        assert_eq!(part2(&network), Err(TraverseError::NoStart("..A")));
+    }
+
+    #[test]
+    fn test_get_node() {
+        assert_eq!(get_node("ABC"), 0x414243);
+        assert_eq!(get_node("AAA"), 0x414141);
+        assert_eq!(get_node("ZZZ"), 0x5a5a5a);
+        assert_eq!(get_address(0x414243), "ABC");
+    }
+}
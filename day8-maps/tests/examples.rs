@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use day8_maps::Day8;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day8.part1(EXAMPLE).unwrap(), Answer::Number(6));
+    assert_eq!(Day8.part2(EXAMPLE).unwrap(), Answer::Number(6));
+}
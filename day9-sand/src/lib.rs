@@ -0,0 +1,146 @@
+//! Day 9: Mirage Maintenance
+//!
+//! Extrapolates sequences of readings by repeatedly taking the differences
+//! between consecutive values.
+
+use std::fmt;
+
+use aoc_core::{Parsed, PrepareResult, Solution, SolveResult};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn name(&self) -> &'static str {
+        "sand"
+    }
+
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(part1(&parse_sequences(input)?).into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(part2(&parse_sequences(input)?).into())
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> PrepareResult<'a> {
+        Ok(Box::new(Parsed::new(
+            parse_sequences(input)?,
+            |sequences| part1(sequences),
+            |sequences| part2(sequences),
+        )))
+    }
+}
+
+/// Sum of the next value of every sequence.
+pub fn part1(sequences: &[Vec<i64>]) -> i64 {
+    sequences.iter().map(|seq| solve_next_for_seq(seq)).sum()
+}
+
+/// Sum of the previous value of every sequence.
+pub fn part2(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|seq| {
+            let mut seq = seq.clone();
+            seq.reverse();
+            solve_next_for_seq(&seq)
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    EmptySequence,
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::EmptySequence => write!(f, "empty sequence"),
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+        }
+    }
+}
+
+pub type ParseError = aoc_core::ParseError<ParseErrorKind>;
+
+/// Parses one sequence of whitespace separated numbers per line.
+pub fn parse_sequences(document: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    aoc_core::parse_lines(document, |line| {
+        let sequence = line
+            .split_whitespace()
+            .map(|number| {
+                number
+                    .parse::<i64>()
+                    .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, line, number))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if sequence.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::EmptySequence, line, line));
+        }
+
+        Ok(sequence)
+    })
+}
+
+/// Extrapolates the value following the sequence. Differences are taken
+/// until they're all zero, or until a single one is left, which is taken to
+/// stay the same. An empty sequence extrapolates to 0.
+pub fn solve_next_for_seq(sequence: &[i64]) -> i64 {
+    let mut sequence = sequence.to_vec();
+    let mut result = 0;
+
+    while let Some(&last) = sequence.last() {
+        result += last;
+        if sequence.len() == 1 || sequence.iter().all(|&x| x == 0) {
+            break;
+        }
+
+        // Calculate the difference between each element
+        sequence = sequence.windows(2).map(|w| w[1] - w[0]).collect();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static SEQUENCE_1: [i64; 6] = [0, 3, 6, 9, 12, 15];
+    static SEQUENCE_2: [i64; 6] = [1, 3, 6, 10, 15, 21];
+    static SEQUENCE_3: [i64; 6] = [10, 13, 16, 21, 30, 45];
+
+    #[test]
+    fn test_solve_next_for_seq() {
+        assert_eq!(solve_next_for_seq(&Vec::from(SEQUENCE_1)), 18);
+        assert_eq!(solve_next_for_seq(&Vec::from(SEQUENCE_2)), 28);
+        assert_eq!(solve_next_for_seq(&Vec::from(SEQUENCE_3)), 68);
+
+        // Differences that never reach zero
+        assert_eq!(solve_next_for_seq(&[5]), 5);
+        assert_eq!(solve_next_for_seq(&[1, 2, 4, 8]), 15);
+        assert_eq!(solve_next_for_seq(&[]), 0);
+    }
+
+    #[test]
+    fn test_parse_sequences() {
+        assert_eq!(parse_sequences("0 3 6\n-1 -2"), Ok(vec![vec![0, 3, 6], vec![-1, -2]]));
+
+        let error = parse_sequences("0 3 6\n1 3 x 10").unwrap_err();
+        assert_eq!((error.kind, error.line, error.column), (ParseErrorKind::InvalidNumber, 2, 5));
+
+        let error = parse_sequences("0 3 6\n\n1 3").unwrap_err();
+        assert_eq!((error.kind, error.line), (ParseErrorKind::EmptySequence, 2));
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Solution};
+use day9_sand::Day9;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(Day9.part1(EXAMPLE).unwrap(), Answer::Number(114));
+    assert_eq!(Day9.part2(EXAMPLE).unwrap(), Answer::Number(2));
+}